@@ -0,0 +1,213 @@
+//! Module Claim
+//!
+//! Module that lets anyone file an infringement claim against a work of the asset registry. The
+//! claimant locks a bond, the owner of the work can answer and an arbiter rules on the claim.
+mod token_contract {
+    soroban_sdk::contractimport!(file = "../../soroban_token_spec.wasm");
+}
+
+use crate::{error::ContractError, storage_types::RegistryDataKey, AuthorshipRecord};
+use soroban_sdk::{contracttype, map, panic_with_error, Address, BytesN, Env, Map};
+
+const REGISTRY_CONFIG_KEY: RegistryDataKey = RegistryDataKey::Config;
+const CLAIMS_KEY: RegistryDataKey = RegistryDataKey::Claims;
+const NEXT_CLAIM_ID_KEY: RegistryDataKey = RegistryDataKey::NextClaimId;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Settings of the asset registry
+pub struct RegistryConfig {
+    /// The address that manages the trusted payment contracts and rules on the claims
+    pub admin: Address,
+    pub arbitrator: ClaimArbitrator,
+    /// The token in which the bonds are paid
+    pub bond_token: BytesN<32>,
+    /// The amount the claimant locks when filing a claim
+    pub bond_amount: i128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ClaimArbitrator {
+    /// Only the admin rules on the claims
+    AdminOnly,
+    /// The given independent arbitrator can also rule on the claims
+    Independent(Address),
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum ClaimStatus {
+    Pending,
+    /// The infringement was proven, the bond is refunded and the work flagged
+    Upheld,
+    /// The infringement wasn't proven, the bond goes to the owner of the work
+    Dismissed,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ClaimResponse {
+    /// The owner of the work didn't answer the claim yet
+    Unanswered,
+    /// Hash of the answer of the owner of the work
+    Answered(BytesN<32>),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct InfringementClaim {
+    pub id: u64,
+    pub claimant: Address,
+    /// Content hash of the work accused of infringement
+    pub content_hash: BytesN<32>,
+    /// Hash of the evidence supporting the claim
+    pub evidence_hash: BytesN<32>,
+    pub bond: i128,
+    /// Ledger timestamp of the filing
+    pub filing_date: u64,
+    pub response: ClaimResponse,
+    pub status: ClaimStatus,
+}
+
+pub(crate) fn write_registry_config(env: &Env, config: &RegistryConfig) {
+    if env.storage().has(&REGISTRY_CONFIG_KEY) {
+        panic_with_error!(env, ContractError::AlreadyInitialized);
+    }
+    env.storage().set(&REGISTRY_CONFIG_KEY, config)
+}
+
+pub(crate) fn read_registry_config(env: &Env) -> RegistryConfig {
+    match env.storage().get(&REGISTRY_CONFIG_KEY) {
+        Some(config) => config.unwrap(),
+        None => panic_with_error!(env, ContractError::NotInitialized),
+    }
+}
+
+fn read_claims(env: &Env) -> Map<u64, InfringementClaim> {
+    match env.storage().get(&CLAIMS_KEY) {
+        Some(claims) => claims.unwrap(),
+        None => map![env],
+    }
+}
+
+fn write_claim(env: &Env, claim: &InfringementClaim) {
+    let mut claims = read_claims(env);
+    claims.set(claim.id, claim.clone());
+    env.storage().set(&CLAIMS_KEY, &claims)
+}
+
+pub(crate) fn read_claim(env: &Env, claim_id: u64) -> InfringementClaim {
+    match read_claims(env).get(claim_id) {
+        Some(claim) => claim.unwrap(),
+        None => panic_with_error!(env, ContractError::ClaimNotFound),
+    }
+}
+
+fn read_pending_claim(env: &Env, claim_id: u64) -> InfringementClaim {
+    let claim = read_claim(env, claim_id);
+    if claim.status != ClaimStatus::Pending {
+        panic_with_error!(env, ContractError::ClaimAlreadyResolved);
+    }
+    claim
+}
+
+fn read_authorship(env: &Env, content_hash: &BytesN<32>) -> AuthorshipRecord {
+    match env
+        .storage()
+        .get(&RegistryDataKey::Authorship(content_hash.clone()))
+    {
+        Some(record) => record.unwrap(),
+        None => panic_with_error!(env, ContractError::WorkNotRegistered),
+    }
+}
+
+fn transfer_bond(env: &Env, token: &BytesN<32>, from: &Address, to: &Address, amount: &i128) {
+    token_contract::Client::new(env, token).xfer(from, to, amount)
+}
+
+pub(crate) fn file_claim(
+    env: &Env,
+    claimant: &Address,
+    content_hash: BytesN<32>,
+    evidence_hash: BytesN<32>,
+) -> u64 {
+    read_authorship(env, &content_hash);
+    let config = read_registry_config(env);
+    if config.bond_amount > 0 {
+        transfer_bond(
+            env,
+            &config.bond_token,
+            claimant,
+            &env.current_contract_address(),
+            &config.bond_amount,
+        );
+    }
+    let claim_id: u64 = match env.storage().get(&NEXT_CLAIM_ID_KEY) {
+        Some(id) => id.unwrap(),
+        None => 0,
+    };
+    write_claim(
+        env,
+        &InfringementClaim {
+            id: claim_id,
+            claimant: claimant.clone(),
+            content_hash,
+            evidence_hash,
+            bond: config.bond_amount,
+            filing_date: env.ledger().timestamp(),
+            response: ClaimResponse::Unanswered,
+            status: ClaimStatus::Pending,
+        },
+    );
+    env.storage().set(&NEXT_CLAIM_ID_KEY, &(claim_id + 1));
+    claim_id
+}
+
+pub(crate) fn respond_to_claim(env: &Env, claim_id: u64, response_hash: BytesN<32>) {
+    let mut claim = read_pending_claim(env, claim_id);
+    read_authorship(env, &claim.content_hash)
+        .creator
+        .require_auth();
+    claim.response = ClaimResponse::Answered(response_hash);
+    write_claim(env, &claim)
+}
+
+pub(crate) fn rule_on_claim(env: &Env, arbiter: &Address, claim_id: u64, upheld: bool) {
+    let config = read_registry_config(env);
+    let is_arbitrator = match config.arbitrator {
+        ClaimArbitrator::AdminOnly => false,
+        ClaimArbitrator::Independent(arbitrator) => arbitrator == *arbiter,
+    };
+    if *arbiter != config.admin && !is_arbitrator {
+        panic_with_error!(env, ContractError::NotClaimArbiter);
+    }
+    arbiter.require_auth();
+    let mut claim = read_pending_claim(env, claim_id);
+    let bond_receiver = if upheld {
+        claim.status = ClaimStatus::Upheld;
+        env.storage().set(
+            &RegistryDataKey::Flagged(claim.content_hash.clone()),
+            &claim_id,
+        );
+        claim.claimant.clone()
+    } else {
+        claim.status = ClaimStatus::Dismissed;
+        read_authorship(env, &claim.content_hash).creator
+    };
+    if claim.bond > 0 {
+        transfer_bond(
+            env,
+            &config.bond_token,
+            &env.current_contract_address(),
+            &bond_receiver,
+            &claim.bond,
+        );
+    }
+    write_claim(env, &claim)
+}
+
+/// Whether an upheld claim flagged the work with the given content hash
+pub(crate) fn is_flagged(env: &Env, content_hash: BytesN<32>) -> bool {
+    env.storage().has(&RegistryDataKey::Flagged(content_hash))
+}
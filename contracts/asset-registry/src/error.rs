@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Error that indicates the registry was already initialized
+    AlreadyInitialized = 1,
+    /// Error that indicates the registry wasn't initialized yet
+    NotInitialized = 2,
+    /// Error that indicates the payment contract isn't allowed to register works in the registry
+    UntrustedContract = 3,
+    /// Error that indicates the content hash isn't registered in the asset registry
+    WorkNotRegistered = 4,
+    /// Error that indicates there is no infringement claim with the given id
+    ClaimNotFound = 5,
+    /// Error that indicates the infringement claim was already upheld or dismissed
+    ClaimAlreadyResolved = 6,
+    /// Error that indicates the address isn't the admin or arbitrator of the asset registry
+    NotClaimArbiter = 7,
+}
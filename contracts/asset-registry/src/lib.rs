@@ -0,0 +1,175 @@
+//! Asset Registry
+//!
+//! Contract that indexes every registered work by its content hash, so anyone can check who
+//! registered it first and when, regardless of the payment contract it was submitted to. It also
+//! links derivative works to the work they derive from and keeps the infringement claims filed
+//! against them.
+#![no_std]
+
+mod claim;
+mod error;
+mod storage_types;
+mod test;
+
+pub use claim::{ClaimArbitrator, ClaimResponse, ClaimStatus, InfringementClaim, RegistryConfig};
+use error::ContractError;
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, vec, Address, BytesN, Env, Vec};
+use storage_types::RegistryDataKey;
+
+pub struct AssetRegistry;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Proof of existence of a work
+pub struct AuthorshipRecord {
+    /// The creator that first registered the work
+    pub creator: Address,
+    /// Ledger timestamp of the first registration
+    pub registration_date: u64,
+    /// The payment contract in which the work was submitted
+    pub contract_id: Address,
+}
+
+#[contractimpl]
+impl AssetRegistry {
+    pub fn init_registry(env: Env, config: RegistryConfig) {
+        config.admin.require_auth();
+        claim::write_registry_config(&env, &config)
+    }
+
+    pub fn register_work(
+        env: Env,
+        source_contract: Address,
+        content_hash: BytesN<32>,
+        creator: Address,
+        parent: Option<BytesN<32>>,
+    ) {
+        // Only payment contracts trusted by the admin can register works, otherwise anyone could
+        // front-run a content hash and claim to be its first author
+        if !is_trusted_contract(&env, &source_contract) {
+            panic_with_error!(&env, ContractError::UntrustedContract);
+        }
+        source_contract.require_auth();
+        let key = RegistryDataKey::Authorship(content_hash.clone());
+        if env.storage().has(&key) {
+            return;
+        }
+        if let Some(parent) = parent {
+            // Only works registered before this one can be its parent, so there are no cycles
+            if env
+                .storage()
+                .has(&RegistryDataKey::Authorship(parent.clone()))
+            {
+                link_derivative_work(&env, &parent, &content_hash);
+            }
+        }
+        let record = AuthorshipRecord {
+            creator,
+            registration_date: env.ledger().timestamp(),
+            contract_id: source_contract,
+        };
+        env.storage().set(&key, &record)
+    }
+
+    /// Allows or forbids the given payment contract to register works. Every payment contract
+    /// that names this registry has to be trusted here, including the ones deployed by a factory
+    pub fn set_trusted_contract(env: Env, contract: Address, trusted: bool) {
+        claim::read_registry_config(&env).admin.require_auth();
+        let key = RegistryDataKey::TrustedContract(contract);
+        if trusted {
+            env.storage().set(&key, &true)
+        } else {
+            env.storage().remove(&key)
+        }
+    }
+
+    pub fn verify_authorship(env: Env, content_hash: BytesN<32>) -> Option<AuthorshipRecord> {
+        env.storage()
+            .get(&RegistryDataKey::Authorship(content_hash))
+            .map(|record| record.unwrap())
+    }
+
+    pub fn is_registered(env: Env, content_hash: BytesN<32>) -> bool {
+        env.storage()
+            .has(&RegistryDataKey::Authorship(content_hash))
+    }
+
+    /// Returns the works the given one derives from, starting by its direct parent
+    pub fn get_ancestry(env: Env, content_hash: BytesN<32>) -> Vec<BytesN<32>> {
+        let mut ancestry = vec![&env];
+        let mut current = content_hash;
+        while let Some(parent) = read_parent(&env, &current) {
+            ancestry.push_back(parent.clone());
+            current = parent;
+        }
+        ancestry
+    }
+
+    /// Returns every work derived directly or indirectly from the given one
+    pub fn get_descendants(env: Env, content_hash: BytesN<32>) -> Vec<BytesN<32>> {
+        let mut descendants = read_children(&env, &content_hash);
+        let mut index = 0;
+        while index < descendants.len() {
+            let child = descendants.get_unchecked(index).unwrap();
+            descendants.append(&read_children(&env, &child));
+            index += 1;
+        }
+        descendants
+    }
+
+    pub fn file_claim(
+        env: Env,
+        claimant: Address,
+        content_hash: BytesN<32>,
+        evidence_hash: BytesN<32>,
+    ) -> u64 {
+        claimant.require_auth();
+        claim::file_claim(&env, &claimant, content_hash, evidence_hash)
+    }
+
+    pub fn respond_to_claim(env: Env, claim_id: u64, response_hash: BytesN<32>) {
+        claim::respond_to_claim(&env, claim_id, response_hash)
+    }
+
+    pub fn rule_on_claim(env: Env, arbiter: Address, claim_id: u64, upheld: bool) {
+        claim::rule_on_claim(&env, &arbiter, claim_id, upheld)
+    }
+
+    pub fn get_claim(env: Env, claim_id: u64) -> InfringementClaim {
+        claim::read_claim(&env, claim_id)
+    }
+
+    pub fn is_flagged(env: Env, content_hash: BytesN<32>) -> bool {
+        claim::is_flagged(&env, content_hash)
+    }
+}
+
+fn is_trusted_contract(env: &Env, contract: &Address) -> bool {
+    env.storage()
+        .has(&RegistryDataKey::TrustedContract(contract.clone()))
+}
+
+fn read_parent(env: &Env, content_hash: &BytesN<32>) -> Option<BytesN<32>> {
+    env.storage()
+        .get(&RegistryDataKey::Parent(content_hash.clone()))
+        .map(|parent| parent.unwrap())
+}
+
+fn read_children(env: &Env, content_hash: &BytesN<32>) -> Vec<BytesN<32>> {
+    match env
+        .storage()
+        .get(&RegistryDataKey::Children(content_hash.clone()))
+    {
+        Some(children) => children.unwrap(),
+        None => vec![env],
+    }
+}
+
+fn link_derivative_work(env: &Env, parent: &BytesN<32>, content_hash: &BytesN<32>) {
+    let mut children = read_children(env, parent);
+    children.push_back(content_hash.clone());
+    env.storage()
+        .set(&RegistryDataKey::Children(parent.clone()), &children);
+    env.storage()
+        .set(&RegistryDataKey::Parent(content_hash.clone()), parent)
+}
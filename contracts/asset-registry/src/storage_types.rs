@@ -0,0 +1,25 @@
+//! Module StorageTypes
+//!
+//! Module that defines the set of keys that can be used to access and store data within the registry.
+use soroban_sdk::{contracttype, Address, BytesN};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum RegistryDataKey {
+    /// To store the `RegistryConfig` with the admin and the infringement claims settings
+    Config,
+    /// To store the `AuthorshipRecord` of the work with the given content hash
+    Authorship(BytesN<32>),
+    /// To store the content hash of the work the given work derives from
+    Parent(BytesN<32>),
+    /// To store the content hashes of the works derived from the given work as `Vec<BytesN<32>>`
+    Children(BytesN<32>),
+    /// To store the infringement claims as `Map<u64, InfringementClaim>`
+    Claims,
+    /// To store the identifier that will be assigned to the next infringement claim
+    NextClaimId,
+    /// To store the id of the upheld claim that flagged the work with the given content hash
+    Flagged(BytesN<32>),
+    /// To store whether the given payment contract is allowed to register works
+    TrustedContract(Address),
+}
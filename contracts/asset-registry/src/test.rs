@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+extern crate std;
+
+use crate::{AssetRegistry, AssetRegistryClient, ClaimArbitrator, RegistryConfig};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+fn create_registry_config(env: &Env, admin: &Address) -> RegistryConfig {
+    RegistryConfig {
+        admin: admin.clone(),
+        arbitrator: ClaimArbitrator::AdminOnly,
+        bond_token: BytesN::from_array(env, &[0; 32]),
+        bond_amount: 0,
+    }
+}
+
+fn create_asset_registry(env: &Env, admin: &Address) -> AssetRegistryClient {
+    let registry = AssetRegistryClient::new(env, &env.register_contract(None, AssetRegistry {}));
+    registry.init_registry(&create_registry_config(env, admin));
+    registry
+}
+
+#[test]
+fn test_trusted_contract_registers_the_first_author_of_a_work() {
+    let env: Env = Default::default();
+    let registry = create_asset_registry(&env, &Address::random(&env));
+    let source_contract = Address::random(&env);
+    let creator = Address::random(&env);
+    let content_hash = BytesN::from_array(&env, &[1; 32]);
+
+    registry.set_trusted_contract(&source_contract, &true);
+    registry.register_work(&source_contract, &content_hash, &creator, &None);
+    registry.register_work(
+        &source_contract,
+        &content_hash,
+        &Address::random(&env),
+        &None,
+    );
+    let record = registry.verify_authorship(&content_hash).unwrap();
+
+    assert_eq!(record.creator, creator);
+    assert_eq!(record.contract_id, source_contract);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_register_work_from_a_contract_no_longer_trusted() {
+    let env: Env = Default::default();
+    let registry = create_asset_registry(&env, &Address::random(&env));
+    let source_contract = Address::random(&env);
+
+    registry.set_trusted_contract(&source_contract, &true);
+    registry.set_trusted_contract(&source_contract, &false);
+    registry.register_work(
+        &source_contract,
+        &BytesN::from_array(&env, &[1; 32]),
+        &Address::random(&env),
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(1))")]
+fn test_init_registry_twice() {
+    let env: Env = Default::default();
+    let registry = create_asset_registry(&env, &Address::random(&env));
+
+    registry.init_registry(&create_registry_config(&env, &Address::random(&env)));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(7))")]
+fn test_rule_on_a_claim_without_being_the_registry_arbiter() {
+    let env: Env = Default::default();
+    let registry = create_asset_registry(&env, &Address::random(&env));
+    let source_contract = Address::random(&env);
+    let content_hash = BytesN::from_array(&env, &[1; 32]);
+
+    registry.set_trusted_contract(&source_contract, &true);
+    registry.register_work(
+        &source_contract,
+        &content_hash,
+        &Address::random(&env),
+        &None,
+    );
+    let claim_id = registry.file_claim(
+        &Address::random(&env),
+        &content_hash,
+        &BytesN::from_array(&env, &[7; 32]),
+    );
+    registry.rule_on_claim(&Address::random(&env), &claim_id, &false);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(4))")]
+fn test_file_a_claim_against_a_work_not_registered() {
+    let env: Env = Default::default();
+    let registry = create_asset_registry(&env, &Address::random(&env));
+
+    registry.file_claim(
+        &Address::random(&env),
+        &BytesN::from_array(&env, &[1; 32]),
+        &BytesN::from_array(&env, &[7; 32]),
+    );
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Error that indicates the factory was already initialized
+    AlreadyInitialized = 1,
+    /// Error that indicates the factory wasn't initialized yet
+    NotInitialized = 2,
+}
@@ -0,0 +1,116 @@
+//! Payment Contract Factory
+//!
+//! Contract that deploys new payment contracts from a stored wasm hash, initializes them in the
+//! same transaction and indexes them by company, project, manager and creator. The deployed
+//! contracts aren't trusted by any asset registry until its admin calls `set_trusted_contract`.
+#![no_std]
+
+mod error;
+mod storage_types;
+mod test;
+
+/// The payment contract interface, `soropay.wasm` has to be rebuilt from the root crate as the
+/// README explains whenever the interface changes
+mod payment_contract {
+    soroban_sdk::contractimport!(file = "soropay.wasm");
+}
+
+use error::ContractError;
+use soroban_sdk::{contractimpl, panic_with_error, vec, Address, Bytes, BytesN, Env, Vec};
+use storage_types::FactoryDataKey;
+
+pub struct PaymentContractFactory;
+
+#[contractimpl]
+impl PaymentContractFactory {
+    pub fn init_factory(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        if env.storage().has(&FactoryDataKey::Admin) {
+            panic_with_error!(env, ContractError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().set(&FactoryDataKey::Admin, &admin);
+        env.storage().set(&FactoryDataKey::WasmHash, &wasm_hash)
+    }
+
+    /// Replaces the wasm used by the payment contracts deployed from now on
+    pub fn update_wasm_hash(env: Env, wasm_hash: BytesN<32>) {
+        read_admin(&env).require_auth();
+        env.storage().set(&FactoryDataKey::WasmHash, &wasm_hash)
+    }
+
+    pub fn deploy_payment_contract(
+        env: Env,
+        salt: BytesN<32>,
+        contract_info: payment_contract::PaymentContractInfo,
+        creator: Address,
+    ) -> BytesN<32> {
+        contract_info.contract_manager.address.require_auth();
+        let wasm_hash: BytesN<32> = match env.storage().get(&FactoryDataKey::WasmHash) {
+            Some(wasm_hash) => wasm_hash.unwrap(),
+            None => panic_with_error!(env, ContractError::NotInitialized),
+        };
+        let contract_id = env
+            .deployer()
+            .with_current_contract(&salt)
+            .deploy(&wasm_hash);
+        payment_contract::Client::new(&env, &contract_id).initialize(&contract_info, &creator);
+
+        index_contract(
+            &env,
+            FactoryDataKey::CompanyContracts(contract_info.company_id),
+            &contract_id,
+        );
+        index_contract(
+            &env,
+            FactoryDataKey::ProjectContracts(contract_info.project_id),
+            &contract_id,
+        );
+        index_contract(
+            &env,
+            FactoryDataKey::ManagerContracts(contract_info.contract_manager.address),
+            &contract_id,
+        );
+        index_contract(
+            &env,
+            FactoryDataKey::CreatorContracts(creator),
+            &contract_id,
+        );
+        contract_id
+    }
+
+    pub fn get_company_contracts(env: Env, company_id: Bytes) -> Vec<BytesN<32>> {
+        read_index(&env, &FactoryDataKey::CompanyContracts(company_id))
+    }
+
+    pub fn get_project_contracts(env: Env, project_id: Bytes) -> Vec<BytesN<32>> {
+        read_index(&env, &FactoryDataKey::ProjectContracts(project_id))
+    }
+
+    pub fn get_manager_contracts(env: Env, manager: Address) -> Vec<BytesN<32>> {
+        read_index(&env, &FactoryDataKey::ManagerContracts(manager))
+    }
+
+    pub fn get_creator_contracts(env: Env, creator: Address) -> Vec<BytesN<32>> {
+        read_index(&env, &FactoryDataKey::CreatorContracts(creator))
+    }
+}
+
+fn read_admin(env: &Env) -> Address {
+    match env.storage().get(&FactoryDataKey::Admin) {
+        Some(admin) => admin.unwrap(),
+        None => panic_with_error!(env, ContractError::NotInitialized),
+    }
+}
+
+fn read_index(env: &Env, key: &FactoryDataKey) -> Vec<BytesN<32>> {
+    match env.storage().get(key) {
+        Some(contracts) => contracts.unwrap(),
+        None => vec![env],
+    }
+}
+
+fn index_contract(env: &Env, key: FactoryDataKey, contract_id: &BytesN<32>) {
+    let mut contracts = read_index(env, &key);
+    contracts.push_back(contract_id.clone());
+    env.storage().set(&key, &contracts)
+}
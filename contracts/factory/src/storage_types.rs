@@ -0,0 +1,21 @@
+//! Module StorageTypes
+//!
+//! Module that defines the set of keys that can be used to access and store data within the factory.
+use soroban_sdk::{contracttype, Address, Bytes};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum FactoryDataKey {
+    /// To store the address that can update the payment contract wasm
+    Admin,
+    /// To store the hash of the installed payment contract wasm
+    WasmHash,
+    /// To store the ids of the payment contracts of the given company as `Vec<BytesN<32>>`
+    CompanyContracts(Bytes),
+    /// To store the ids of the payment contracts of the given project as `Vec<BytesN<32>>`
+    ProjectContracts(Bytes),
+    /// To store the ids of the payment contracts managed by the given address
+    ManagerContracts(Address),
+    /// To store the ids of the payment contracts signed by the given creator
+    CreatorContracts(Address),
+}
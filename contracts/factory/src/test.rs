@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+extern crate std;
+
+use crate::{
+    payment_contract::{
+        ArbitrationPanel, AssetMetadata, AssetPreview, AssetReference, AssetSubmission,
+        Client as PaymentContractClient, ContractManager, ContractType, KillFee, LinkedContract,
+        MediaExtent, MediaType, OfferedLicense, PaymentContractInfo, PaymentMethod,
+        WASM as PAYMENT_CONTRACT_WASM,
+    },
+    PaymentContractFactory, PaymentContractFactoryClient,
+};
+use soroban_sdk::{map, testutils::Address as _, vec, Address, BytesN, Env, IntoVal};
+use soropay_asset_registry::{AssetRegistry, AssetRegistryClient, ClaimArbitrator, RegistryConfig};
+
+fn create_factory(env: &Env) -> PaymentContractFactoryClient {
+    let factory = PaymentContractFactoryClient::new(
+        env,
+        &env.register_contract(None, PaymentContractFactory {}),
+    );
+    factory.init_factory(
+        &Address::random(env),
+        &env.install_contract_wasm(PAYMENT_CONTRACT_WASM),
+    );
+    factory
+}
+
+fn create_payment_contract_info(
+    env: &Env,
+    contract_manager_address: &Address,
+) -> PaymentContractInfo {
+    PaymentContractInfo {
+        contract_manager: ContractManager {
+            address: contract_manager_address.clone(),
+            name: "John Doe".into_val(env),
+            job_position: "Product owner".into_val(env),
+            physical_address: "Some address".into_val(env),
+        },
+        company_id: "ID-001".into_val(env),
+        project_id: "ID-002".into_val(env),
+        contract_name: "Test Contract Name".into_val(env),
+        payment_method: PaymentMethod::Native(BytesN::from_array(env, &[0; 32])),
+        asset_payment_amount: 5,
+        creation_date: 1681917160,
+        start_date: 1682003560,
+        deadline: 1684546903,
+        scope_of_work: "scope_of_work text".into_val(env),
+        rights_royalties: "rights_royalties text".into_val(env),
+        payment_time: 0,
+        contract_type: ContractType::Milestones,
+        arbitration_panel: ArbitrationPanel {
+            arbitrators: vec![env],
+            voting_period: 0,
+            arbitration_fee: 0,
+        },
+        dispute_response_window: 0,
+        asset_registry: LinkedContract::None,
+        license_terms: OfferedLicense::None,
+        project_registry: LinkedContract::None,
+        offer_expiry: 0,
+        budget: 20,
+        kill_fee: KillFee::None,
+        review_window: 0,
+    }
+}
+
+#[test]
+fn test_deployed_payment_contract_is_initialized_and_indexed() {
+    let env: Env = Default::default();
+    let contract_manager_address = Address::random(&env);
+    let creator_address = Address::random(&env);
+    let factory = create_factory(&env);
+    let contract_info = create_payment_contract_info(&env, &contract_manager_address);
+
+    let contract_id = factory.deploy_payment_contract(
+        &BytesN::from_array(&env, &[1; 32]),
+        &contract_info,
+        &creator_address,
+    );
+    let payment_contract = PaymentContractClient::new(&env, &contract_id);
+
+    assert_eq!(payment_contract.get_payment_contract_info(), contract_info);
+    assert_eq!(
+        factory.get_company_contracts(&contract_info.company_id),
+        vec![&env, contract_id.clone()]
+    );
+    assert_eq!(
+        factory.get_project_contracts(&contract_info.project_id),
+        vec![&env, contract_id.clone()]
+    );
+    assert_eq!(
+        factory.get_manager_contracts(&contract_manager_address),
+        vec![&env, contract_id.clone()]
+    );
+    assert_eq!(
+        factory.get_creator_contracts(&creator_address),
+        vec![&env, contract_id]
+    );
+    assert_eq!(
+        factory.get_creator_contracts(&Address::random(&env)),
+        vec![&env]
+    );
+}
+
+#[test]
+fn test_deployed_payment_contract_registers_works_once_trusted_by_the_registry() {
+    let env: Env = Default::default();
+    let creator_address = Address::random(&env);
+    let content_hash = BytesN::from_array(&env, &[1; 32]);
+    let factory = create_factory(&env);
+    let registry_id = env.register_contract(None, AssetRegistry {});
+    let registry = AssetRegistryClient::new(&env, &registry_id);
+    registry.init_registry(&RegistryConfig {
+        admin: Address::random(&env),
+        arbitrator: ClaimArbitrator::AdminOnly,
+        bond_token: BytesN::from_array(&env, &[0; 32]),
+        bond_amount: 0,
+    });
+    let mut contract_info = create_payment_contract_info(&env, &Address::random(&env));
+    contract_info.asset_registry = LinkedContract::Id(registry_id);
+
+    let contract_id = factory.deploy_payment_contract(
+        &BytesN::from_array(&env, &[1; 32]),
+        &contract_info,
+        &creator_address,
+    );
+    registry.set_trusted_contract(&Address::from_contract_id(&env, &contract_id), &true);
+    let payment_contract = PaymentContractClient::new(&env, &contract_id);
+    payment_contract.sign_contract(
+        &creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(
+        &creator_address,
+        &map![
+            &env,
+            (
+                "ASSET-ID-1".into_val(&env),
+                AssetSubmission {
+                    asset_url: "asset-1-url".into_val(&env),
+                    content_hash: content_hash.clone(),
+                    metadata: AssetMetadata {
+                        title: "Asset 1".into_val(&env),
+                        media_type: MediaType::Image,
+                        extent: MediaExtent::Unspecified,
+                        file_size: 2097152,
+                        preview: AssetPreview::None,
+                    },
+                    parent: AssetReference::Original,
+                }
+            )
+        ],
+    );
+
+    assert_eq!(
+        registry.verify_authorship(&content_hash).unwrap().creator,
+        creator_address
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(1))")]
+fn test_initialize_the_factory_twice() {
+    let env: Env = Default::default();
+    let factory = create_factory(&env);
+
+    factory.init_factory(&Address::random(&env), &BytesN::from_array(&env, &[2; 32]));
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Error that indicates there is already a project with the given id
+    ProjectAlreadyExists = 1,
+    /// Error that indicates there is no project with the given id
+    ProjectNotFound = 2,
+    /// Error that indicates the amount is not positive or exceeds the budget of the project or the
+    /// contract
+    BudgetExceeded = 3,
+}
@@ -0,0 +1,200 @@
+//! Project Registry
+//!
+//! Contract that holds the budget of a project in escrow. The project owner commits part of it to
+//! each payment contract of the project, and those contracts draw from it to pay their creators
+//! and return what they drew but didn't pay.
+#![no_std]
+
+mod error;
+mod storage_types;
+mod test;
+
+mod token_contract {
+    soroban_sdk::contractimport!(file = "../../soroban_token_spec.wasm");
+}
+
+use error::ContractError;
+use soroban_sdk::{contractimpl, contracttype, panic_with_error, Address, Bytes, BytesN, Env};
+use storage_types::ProjectDataKey;
+
+pub struct ProjectRegistry;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct Project {
+    pub owner: Address,
+    /// The token in which the budget is held, it must be the payment token of the contracts
+    pub token: BytesN<32>,
+    pub total_budget: i128,
+    /// The part of the budget already assigned to payment contracts
+    pub committed: i128,
+    /// The part of the budget already paid to creators
+    pub paid: i128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The part of the project budget assigned to a payment contract
+pub struct ContractBudget {
+    pub committed: i128,
+    pub paid: i128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct BudgetReport {
+    pub total_budget: i128,
+    pub committed: i128,
+    pub paid: i128,
+    /// The budget not committed to any payment contract yet
+    pub remaining: i128,
+}
+
+#[contractimpl]
+impl ProjectRegistry {
+    pub fn create_project(
+        env: Env,
+        owner: Address,
+        project_id: Bytes,
+        token: BytesN<32>,
+        total_budget: i128,
+    ) {
+        let key = ProjectDataKey::Project(project_id);
+        if env.storage().has(&key) {
+            panic_with_error!(env, ContractError::ProjectAlreadyExists);
+        }
+        if total_budget <= 0 {
+            panic_with_error!(env, ContractError::BudgetExceeded);
+        }
+        owner.require_auth();
+        transfer_token(
+            &env,
+            &token,
+            &owner,
+            &env.current_contract_address(),
+            &total_budget,
+        );
+        let project = Project {
+            owner,
+            token,
+            total_budget,
+            committed: 0,
+            paid: 0,
+        };
+        env.storage().set(&key, &project)
+    }
+
+    /// Assigns part of the project budget to a payment contract of the project
+    pub fn commit_budget(env: Env, project_id: Bytes, contract: Address, amount: i128) {
+        let mut project = read_project(&env, &project_id);
+        project.owner.require_auth();
+        if amount <= 0 || project.committed + amount > project.total_budget {
+            panic_with_error!(env, ContractError::BudgetExceeded);
+        }
+        let mut budget = read_contract_budget(&env, &project_id, &contract);
+        budget.committed += amount;
+        project.committed += amount;
+        write_contract_budget(&env, &project_id, &contract, &budget);
+        write_project(&env, &project_id, &project)
+    }
+
+    /// Moves funds of the budget committed to the calling payment contract into it
+    pub fn draw_budget(env: Env, project_id: Bytes, contract: Address, amount: i128) {
+        contract.require_auth();
+        let mut project = read_project(&env, &project_id);
+        let mut budget = read_contract_budget(&env, &project_id, &contract);
+        if amount <= 0 || budget.paid + amount > budget.committed {
+            panic_with_error!(env, ContractError::BudgetExceeded);
+        }
+        transfer_token(
+            &env,
+            &project.token,
+            &env.current_contract_address(),
+            &contract,
+            &amount,
+        );
+        budget.paid += amount;
+        project.paid += amount;
+        write_contract_budget(&env, &project_id, &contract, &budget);
+        write_project(&env, &project_id, &project)
+    }
+
+    /// Moves funds the calling payment contract drew but didn't pay back into the project budget.
+    /// The contract must allow the registry to spend the amount before calling it
+    pub fn return_budget(env: Env, project_id: Bytes, contract: Address, amount: i128) {
+        contract.require_auth();
+        let mut project = read_project(&env, &project_id);
+        let mut budget = read_contract_budget(&env, &project_id, &contract);
+        if amount <= 0 || amount > budget.paid {
+            panic_with_error!(env, ContractError::BudgetExceeded);
+        }
+        token_contract::Client::new(&env, &project.token).xfer_from(
+            &env.current_contract_address(),
+            &contract,
+            &env.current_contract_address(),
+            &amount,
+        );
+        budget.paid -= amount;
+        project.paid -= amount;
+        write_contract_budget(&env, &project_id, &contract, &budget);
+        write_project(&env, &project_id, &project)
+    }
+
+    pub fn get_budget_report(env: Env, project_id: Bytes) -> BudgetReport {
+        let project = read_project(&env, &project_id);
+        BudgetReport {
+            total_budget: project.total_budget,
+            committed: project.committed,
+            paid: project.paid,
+            remaining: project.total_budget - project.committed,
+        }
+    }
+
+    pub fn get_contract_budget(env: Env, project_id: Bytes, contract: Address) -> ContractBudget {
+        read_contract_budget(&env, &project_id, &contract)
+    }
+}
+
+fn read_project(env: &Env, project_id: &Bytes) -> Project {
+    match env
+        .storage()
+        .get(&ProjectDataKey::Project(project_id.clone()))
+    {
+        Some(project) => project.unwrap(),
+        None => panic_with_error!(env, ContractError::ProjectNotFound),
+    }
+}
+
+fn write_project(env: &Env, project_id: &Bytes, project: &Project) {
+    env.storage()
+        .set(&ProjectDataKey::Project(project_id.clone()), project)
+}
+
+fn read_contract_budget(env: &Env, project_id: &Bytes, contract: &Address) -> ContractBudget {
+    match env.storage().get(&ProjectDataKey::ContractBudget(
+        project_id.clone(),
+        contract.clone(),
+    )) {
+        Some(budget) => budget.unwrap(),
+        None => ContractBudget {
+            committed: 0,
+            paid: 0,
+        },
+    }
+}
+
+fn write_contract_budget(
+    env: &Env,
+    project_id: &Bytes,
+    contract: &Address,
+    budget: &ContractBudget,
+) {
+    env.storage().set(
+        &ProjectDataKey::ContractBudget(project_id.clone(), contract.clone()),
+        budget,
+    )
+}
+
+fn transfer_token(env: &Env, token: &BytesN<32>, from: &Address, to: &Address, amount: &i128) {
+    token_contract::Client::new(env, token).xfer(from, to, amount)
+}
@@ -0,0 +1,13 @@
+//! Module StorageTypes
+//!
+//! Module that defines the set of keys that can be used to access and store data within the registry.
+use soroban_sdk::{contracttype, Address, Bytes};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ProjectDataKey {
+    /// To store the `Project` with the given id
+    Project(Bytes),
+    /// To store the `ContractBudget` of the given payment contract within the given project
+    ContractBudget(Bytes, Address),
+}
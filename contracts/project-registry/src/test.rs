@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+extern crate std;
+
+use crate::{ProjectRegistry, ProjectRegistryClient};
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, IntoVal};
+
+mod token_contract {
+    soroban_sdk::contractimport!(file = "../../soroban_token_spec.wasm");
+}
+
+fn create_project_registry(
+    env: &Env,
+    owner: &Address,
+    project_id: &Bytes,
+    total_budget: i128,
+) -> (ProjectRegistryClient, BytesN<32>) {
+    let token_admin = Address::random(env);
+    let token_id = env.register_stellar_asset_contract(token_admin.clone());
+    token_contract::Client::new(env, &token_id).mint(&token_admin, owner, &1000_i128);
+    let registry =
+        ProjectRegistryClient::new(env, &env.register_contract(None, ProjectRegistry {}));
+    registry.create_project(owner, project_id, &token_id, &total_budget);
+    (registry, token_id)
+}
+
+#[test]
+fn test_commit_budget_to_the_contracts_of_a_project() {
+    let env: Env = Default::default();
+    let project_id: Bytes = "ID-001".into_val(&env);
+    let contract = Address::random(&env);
+    let (registry, _) = create_project_registry(&env, &Address::random(&env), &project_id, 100);
+
+    registry.commit_budget(&project_id, &contract, &30);
+    registry.draw_budget(&project_id, &contract, &10);
+    let report = registry.get_budget_report(&project_id);
+    let budget = registry.get_contract_budget(&project_id, &contract);
+
+    assert_eq!(report.committed, 30);
+    assert_eq!(report.paid, 10);
+    assert_eq!(report.remaining, 70);
+    assert_eq!(budget.committed, 30);
+    assert_eq!(budget.paid, 10);
+}
+
+#[test]
+fn test_return_the_budget_a_contract_did_not_pay() {
+    let env: Env = Default::default();
+    let project_id: Bytes = "ID-001".into_val(&env);
+    let contract = Address::random(&env);
+    let (registry, token_id) =
+        create_project_registry(&env, &Address::random(&env), &project_id, 100);
+    let token = token_contract::Client::new(&env, &token_id);
+    let registry_address = Address::from_contract_id(&env, &registry.contract_id);
+
+    registry.commit_budget(&project_id, &contract, &30);
+    registry.draw_budget(&project_id, &contract, &20);
+    token.incr_allow(&contract, &registry_address, &15);
+    registry.return_budget(&project_id, &contract, &15);
+    let report = registry.get_budget_report(&project_id);
+    let budget = registry.get_contract_budget(&project_id, &contract);
+
+    assert_eq!(report.paid, 5);
+    assert_eq!(report.remaining, 70);
+    assert_eq!(budget.committed, 30);
+    assert_eq!(budget.paid, 5);
+    assert_eq!(token.balance(&contract), 5);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_return_more_than_the_contract_drew() {
+    let env: Env = Default::default();
+    let project_id: Bytes = "ID-001".into_val(&env);
+    let contract = Address::random(&env);
+    let (registry, _) = create_project_registry(&env, &Address::random(&env), &project_id, 100);
+
+    registry.commit_budget(&project_id, &contract, &30);
+    registry.draw_budget(&project_id, &contract, &10);
+    registry.return_budget(&project_id, &contract, &20);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_commit_more_than_the_project_budget() {
+    let env: Env = Default::default();
+    let project_id: Bytes = "ID-001".into_val(&env);
+    let (registry, _) = create_project_registry(&env, &Address::random(&env), &project_id, 100);
+
+    registry.commit_budget(&project_id, &Address::random(&env), &60);
+    registry.commit_budget(&project_id, &Address::random(&env), &60);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_draw_a_negative_amount_from_the_contract_budget() {
+    let env: Env = Default::default();
+    let project_id: Bytes = "ID-001".into_val(&env);
+    let contract = Address::random(&env);
+    let (registry, _) = create_project_registry(&env, &Address::random(&env), &project_id, 100);
+
+    registry.commit_budget(&project_id, &contract, &30);
+    registry.draw_budget(&project_id, &contract, &-10);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_create_a_project_with_a_negative_budget() {
+    let env: Env = Default::default();
+    create_project_registry(&env, &Address::random(&env), &"ID-001".into_val(&env), -100);
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    /// Error that indicates there is no published template with the given name and version
+    TemplateNotFound = 1,
+}
@@ -0,0 +1,64 @@
+//! Template Registry
+//!
+//! Contract where companies publish the reusable terms of their payment contracts. Each
+//! publication under the same name creates a new version, and published versions never change.
+#![no_std]
+
+mod error;
+mod storage_types;
+mod test;
+
+use error::ContractError;
+use soroban_sdk::{contractimpl, panic_with_error, vec, xdr::ToXdr, Address, Bytes, Env, Vec};
+pub use soropay_types::{ContractTemplate, TemplateTerms};
+use storage_types::TemplateDataKey;
+
+pub struct TemplateRegistry;
+
+#[contractimpl]
+impl TemplateRegistry {
+    /// Publishes a new version of the template with the given name, returning its version
+    pub fn publish_template(
+        env: Env,
+        publisher: Address,
+        name: Bytes,
+        terms: TemplateTerms,
+    ) -> u32 {
+        publisher.require_auth();
+        let key = TemplateDataKey::Templates(publisher, name);
+        let mut versions = read_versions(&env, &key);
+        let version = versions.len() + 1;
+        versions.push_back(ContractTemplate {
+            version,
+            terms_hash: env.crypto().sha256(&terms.clone().to_xdr(&env)),
+            publication_date: env.ledger().timestamp(),
+            terms,
+        });
+        env.storage().set(&key, &versions);
+        version
+    }
+
+    pub fn get_template(
+        env: Env,
+        publisher: Address,
+        name: Bytes,
+        version: u32,
+    ) -> ContractTemplate {
+        let versions = read_versions(&env, &TemplateDataKey::Templates(publisher, name));
+        if version == 0 || version > versions.len() {
+            panic_with_error!(env, ContractError::TemplateNotFound);
+        }
+        versions.get_unchecked(version - 1).unwrap()
+    }
+
+    pub fn get_latest_version(env: Env, publisher: Address, name: Bytes) -> u32 {
+        read_versions(&env, &TemplateDataKey::Templates(publisher, name)).len()
+    }
+}
+
+fn read_versions(env: &Env, key: &TemplateDataKey) -> Vec<ContractTemplate> {
+    match env.storage().get(key) {
+        Some(versions) => versions.unwrap(),
+        None => vec![env],
+    }
+}
@@ -0,0 +1,11 @@
+//! Module StorageTypes
+//!
+//! Module that defines the set of keys that can be used to access and store data within the registry.
+use soroban_sdk::{contracttype, Address, Bytes};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum TemplateDataKey {
+    /// To store the published versions of the given publisher template as `Vec<ContractTemplate>`
+    Templates(Address, Bytes),
+}
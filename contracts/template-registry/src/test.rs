@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+extern crate std;
+
+use crate::{TemplateRegistry, TemplateRegistryClient, TemplateTerms};
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env, IntoVal};
+use soropay_types::{ArbitrationPanel, ContractType, KillFee, OfferedLicense};
+
+fn create_template_terms(env: &Env, scope_of_work: &str) -> TemplateTerms {
+    TemplateTerms {
+        company_id: "ID-001".into_val(env),
+        scope_of_work: scope_of_work.into_val(env),
+        rights_royalties: "rights_royalties text".into_val(env),
+        contract_type: ContractType::FixedPrice,
+        asset_payment_amount: 5,
+        payment_time: 0,
+        arbitration_panel: ArbitrationPanel {
+            arbitrators: vec![env],
+            voting_period: 0,
+            arbitration_fee: 0,
+        },
+        dispute_response_window: 604800,
+        license_terms: OfferedLicense::None,
+        kill_fee: KillFee::Flat(10),
+        review_window: 0,
+    }
+}
+
+#[test]
+fn test_publishing_a_template_keeps_its_previous_versions() {
+    let env: Env = Default::default();
+    let publisher = Address::random(&env);
+    let name: Bytes = "Illustration".into_val(&env);
+    let registry =
+        TemplateRegistryClient::new(&env, &env.register_contract(None, TemplateRegistry {}));
+
+    let first_version =
+        registry.publish_template(&publisher, &name, &create_template_terms(&env, "First"));
+    let second_version =
+        registry.publish_template(&publisher, &name, &create_template_terms(&env, "Second"));
+    let first_template = registry.get_template(&publisher, &name, &first_version);
+
+    assert_eq!(first_version, 1);
+    assert_eq!(second_version, 2);
+    assert_eq!(registry.get_latest_version(&publisher, &name), 2);
+    assert_eq!(first_template.terms, create_template_terms(&env, "First"));
+    assert_ne!(
+        first_template.terms_hash,
+        registry.get_template(&publisher, &name, &2).terms_hash
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(1))")]
+fn test_get_a_template_version_not_published() {
+    let env: Env = Default::default();
+    let publisher = Address::random(&env);
+    let name: Bytes = "Illustration".into_val(&env);
+    let registry =
+        TemplateRegistryClient::new(&env, &env.register_contract(None, TemplateRegistry {}));
+
+    registry.publish_template(&publisher, &name, &create_template_terms(&env, "First"));
+    registry.get_template(&publisher, &name, &2);
+}
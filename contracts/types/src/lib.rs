@@ -0,0 +1,96 @@
+//! Soropay Types
+//!
+//! Terms shared by the payment contract and the template registry, so a template publishes the
+//! same terms the contracts created from it store.
+#![no_std]
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Vec};
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum ContractType {
+    FixedPrice,
+    Milestones,
+    Licensing,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct ArbitrationPanel {
+    /// The arbitrators that vote on disputes, a ruling needs the majority of them. Disputes can't
+    /// be opened when the panel is empty
+    pub arbitrators: Vec<Address>,
+    /// Time the panel has to vote once the dispute response window ends
+    pub voting_period: u64,
+    /// The fee paid to each arbitrator that votes, funded by the parties deposits
+    pub arbitration_fee: i128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum OfferedLicense {
+    /// The contract doesn't grant licenses, the rights are assigned to the company
+    None,
+    Terms(LicenseTerms),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct LicenseTerms {
+    /// Description of the allowed uses
+    pub scope: Bytes,
+    /// Codes of the territories where the asset can be used, empty for worldwide
+    pub territories: Vec<Bytes>,
+    /// Bitmask of the usage channels where the asset can be used
+    pub channels: u32,
+    pub exclusive: bool,
+    /// How long each license term lasts, 0 for a perpetual license
+    pub duration: u64,
+    /// The fee paid into the contract to renew a license for another term
+    pub renewal_fee: i128,
+    /// Whether the license holders can issue sublicenses
+    pub sublicensable: bool,
+    /// Share of every license resale or sublicense paid to the creator, in basis points
+    pub royalty_rate: u32,
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+/// What the company pays to the creator if it cancels the signed contract
+pub enum KillFee {
+    None,
+    /// Basis points of the budget not paid yet
+    Percentage(u32),
+    Flat(i128),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The terms shared by every contract created from a template
+pub struct TemplateTerms {
+    pub company_id: Bytes,
+    pub scope_of_work: Bytes,
+    pub rights_royalties: Bytes,
+    pub contract_type: ContractType,
+    /// The default payment amount for each approved asset
+    pub asset_payment_amount: i128,
+    /// When the payment is due, it decides whether the prepayment fee applies
+    pub payment_time: u64,
+    pub arbitration_panel: ArbitrationPanel,
+    pub dispute_response_window: u64,
+    pub license_terms: OfferedLicense,
+    pub kill_fee: KillFee,
+    pub review_window: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A published version of a template
+pub struct ContractTemplate {
+    pub version: u32,
+    /// SHA-256 of the published terms
+    pub terms_hash: BytesN<32>,
+    /// Ledger timestamp of the publication
+    pub publication_date: u64,
+    pub terms: TemplateTerms,
+}
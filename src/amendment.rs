@@ -0,0 +1,157 @@
+//! Module Amendment
+//!
+//! Module that lets one party propose changes to the contract terms once it is signed. The changes
+//! are only applied when the counterparty signs the hash of the amended terms.
+use crate::{
+    delegation::{require_creator_or_delegate, DelegationScope},
+    error::ContractError,
+    metadata::write_accepted_terms_hash,
+    payment_contract_info::{
+        get_contract_info, get_contract_manager_address, write_contract_info, OfferedLicense,
+        PaymentContractInfo,
+    },
+    storage_types::DataKey,
+};
+use soroban_sdk::{
+    contracttype, panic_with_error, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec,
+};
+
+const AMENDMENTS_KEY: DataKey = DataKey::Amendments;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A term an amendment changes with its new value, the terms not listed keep their current value
+pub enum TermChange {
+    Deadline(u64),
+    AssetPaymentAmount(i128),
+    PaymentTime(u64),
+    ScopeOfWork(Bytes),
+    RightsRoyalties(Bytes),
+    LicenseTerms(OfferedLicense),
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum AmendmentStatus {
+    Proposed,
+    Accepted,
+    Declined,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct Amendment {
+    pub proposer: Address,
+    pub changes: Vec<TermChange>,
+    /// Ledger timestamp of the proposal
+    pub proposal_date: u64,
+    /// Hash of the contract terms once the changes are applied, the one the counterparty signs
+    pub terms_hash: BytesN<32>,
+    pub status: AmendmentStatus,
+    /// Ledger timestamp in which the counterparty accepted or declined the amendment, 0 while the
+    /// amendment is proposed
+    pub response_date: u64,
+}
+
+fn apply_changes(
+    changes: &Vec<TermChange>,
+    mut contract_info: PaymentContractInfo,
+) -> PaymentContractInfo {
+    for change in changes.iter() {
+        match change.unwrap() {
+            TermChange::Deadline(deadline) => contract_info.deadline = deadline,
+            TermChange::AssetPaymentAmount(amount) => contract_info.asset_payment_amount = amount,
+            TermChange::PaymentTime(payment_time) => contract_info.payment_time = payment_time,
+            TermChange::ScopeOfWork(scope_of_work) => contract_info.scope_of_work = scope_of_work,
+            TermChange::RightsRoyalties(rights_royalties) => {
+                contract_info.rights_royalties = rights_royalties
+            }
+            TermChange::LicenseTerms(license_terms) => contract_info.license_terms = license_terms,
+        }
+    }
+    contract_info
+}
+
+pub(crate) fn read_amendments(env: &Env) -> Vec<Amendment> {
+    match env.storage().get(&AMENDMENTS_KEY) {
+        Some(amendments) => amendments.unwrap(),
+        None => vec![env],
+    }
+}
+
+fn read_pending_amendment(env: &Env) -> Option<Amendment> {
+    let amendment = read_amendments(env).last()?.unwrap();
+    match amendment.status {
+        AmendmentStatus::Proposed => Some(amendment),
+        _ => None,
+    }
+}
+
+fn update_pending_amendment(env: &Env, amendment: &Amendment) {
+    let mut amendments = read_amendments(env);
+    amendments.set(amendments.len() - 1, amendment.clone());
+    env.storage().set(&AMENDMENTS_KEY, &amendments)
+}
+
+/// Checks the caller is a party of the contract, returning whether it's the company side
+pub(crate) fn require_party(env: &Env, caller: &Address) -> bool {
+    if *caller == get_contract_manager_address(env) {
+        caller.require_auth();
+        return true;
+    }
+    require_creator_or_delegate(env, caller, DelegationScope::Sign);
+    false
+}
+
+pub(crate) fn propose_amendment(env: &Env, proposer: &Address, changes: Vec<TermChange>) {
+    require_party(env, proposer);
+    if read_pending_amendment(env).is_some() {
+        panic_with_error!(env, ContractError::AmendmentPending);
+    }
+    let amended_info = apply_changes(&changes, get_contract_info(env));
+    let mut amendments = read_amendments(env);
+    amendments.push_back(Amendment {
+        proposer: proposer.clone(),
+        changes,
+        proposal_date: env.ledger().timestamp(),
+        terms_hash: env.crypto().sha256(&amended_info.to_xdr(env)),
+        status: AmendmentStatus::Proposed,
+        response_date: 0,
+    });
+    env.storage().set(&AMENDMENTS_KEY, &amendments)
+}
+
+fn read_amendment_for_counterparty(env: &Env, signer: &Address) -> Amendment {
+    let amendment = match read_pending_amendment(env) {
+        Some(amendment) => amendment,
+        None => panic_with_error!(env, ContractError::NoPendingAmendment),
+    };
+    let signer_is_company = require_party(env, signer);
+    let proposer_is_company = amendment.proposer == get_contract_manager_address(env);
+    if signer_is_company == proposer_is_company {
+        panic_with_error!(env, ContractError::NotCounterparty);
+    }
+    amendment
+}
+
+pub(crate) fn accept_amendment(env: &Env, signer: &Address, terms_hash: BytesN<32>) {
+    let mut amendment = read_amendment_for_counterparty(env, signer);
+    if terms_hash != amendment.terms_hash {
+        panic_with_error!(env, ContractError::TermsHashMismatch);
+    }
+    write_contract_info(
+        env,
+        &apply_changes(&amendment.changes, get_contract_info(env)),
+    );
+    write_accepted_terms_hash(env, &terms_hash);
+    amendment.status = AmendmentStatus::Accepted;
+    amendment.response_date = env.ledger().timestamp();
+    update_pending_amendment(env, &amendment)
+}
+
+pub(crate) fn decline_amendment(env: &Env, signer: &Address) {
+    let mut amendment = read_amendment_for_counterparty(env, signer);
+    amendment.status = AmendmentStatus::Declined;
+    amendment.response_date = env.ledger().timestamp();
+    update_pending_amendment(env, &amendment)
+}
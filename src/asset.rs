@@ -1,9 +1,15 @@
 use crate::{
-    error::ContractError, payment::execute_payment, payment_contract_info::get_payment_time,
+    error::ContractError,
+    history::{record_asset_event, state_action, AssetAction},
+    payment::execute_payment,
+    payment_contract_info::{get_contract_manager_address, get_creator, get_payment_time},
+    quorum::{clear_review_votes, has_review_quorum},
+    registry::{is_registered_work, register_work},
     storage_types::DataKey,
+    PaymentContractClient,
 };
 
-use soroban_sdk::{contracttype, map, panic_with_error, Bytes, Env, Map, Vec};
+use soroban_sdk::{contracttype, map, panic_with_error, Address, Bytes, BytesN, Env, Map, Vec};
 
 const CREATOR_ASSETS_KEY: DataKey = DataKey::CreatorAssets;
 
@@ -11,8 +17,91 @@ const CREATOR_ASSETS_KEY: DataKey = DataKey::CreatorAssets;
 #[contracttype]
 pub struct Asset {
     pub asset_url: Bytes,
+    /// SHA-256 of the submitted file, it can't change once the asset is approved
+    pub content_hash: BytesN<32>,
+    pub metadata: AssetMetadata,
+    /// The ledger timestamp in which the asset was submitted
     pub submission_date: u64,
     pub state: AssetState,
+    /// The asset this one is an edit, remix or translation of
+    pub parent: AssetReference,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The data submitted by the creator for each asset
+pub struct AssetSubmission {
+    pub asset_url: Bytes,
+    /// SHA-256 of the submitted file
+    pub content_hash: BytesN<32>,
+    pub metadata: AssetMetadata,
+    /// The approved asset this one derives from, if any
+    pub parent: AssetReference,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Reference to the original work of a derivative asset
+pub enum AssetReference {
+    /// The asset is an original work
+    Original,
+    /// Asset with the given id submitted to this contract
+    Local(Bytes),
+    /// Asset with the given id submitted to the payment contract with the given id
+    External(BytesN<32>, Bytes),
+    /// Work registered in the asset registry with the given content hash
+    ContentHash(BytesN<32>),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Descriptive data that lets reviewers triage an asset without fetching the file
+pub struct AssetMetadata {
+    pub title: Bytes,
+    pub media_type: MediaType,
+    pub extent: MediaExtent,
+    /// Size of the file in bytes
+    pub file_size: u64,
+    pub preview: AssetPreview,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum AssetPreview {
+    /// The creator didn't attach a preview
+    None,
+    /// SHA-256 of a low resolution preview of the asset
+    Hash(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum MediaType {
+    Image,
+    Video,
+    Audio,
+    Document,
+    Model3D,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum MediaExtent {
+    /// The asset has no meaningful duration or dimensions
+    Unspecified,
+    /// Length in seconds of a video or audio
+    Duration(u64),
+    /// Size of an image, video or 3D model
+    Dimensions(Dimensions),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct Dimensions {
+    pub width: u32,
+    pub height: u32,
+    /// Only used by 3D models
+    pub depth: u32,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -21,42 +110,165 @@ pub enum AssetState {
     InReview,
     Approved,
     Rejected,
+    PaymentPending,
     Paid,
 }
 
 impl Asset {
-    fn new(asset_url: Bytes, submission_date: u64) -> Self {
+    fn new(submission: AssetSubmission, submission_date: u64) -> Self {
         Asset {
-            asset_url,
+            asset_url: submission.asset_url,
+            content_hash: submission.content_hash,
+            metadata: submission.metadata,
             submission_date,
             state: AssetState::InReview,
+            parent: submission.parent,
         }
     }
+
+    fn is_locked(&self) -> bool {
+        matches!(
+            self.state,
+            AssetState::Approved | AssetState::PaymentPending | AssetState::Paid
+        )
+    }
 }
 
-pub(crate) fn store_assets(env: &Env, asset_ids: Map<Bytes, Bytes>, submission_date: u64) {
-    let mut assets: Map<Bytes, Asset> = map![env];
-    for asset_url in asset_ids.iter() {
-        let (id, url) = asset_url.unwrap();
-        let asset = Asset::new(url, submission_date);
+pub(crate) fn store_assets(
+    env: &Env,
+    submitter: &Address,
+    submissions: Map<Bytes, AssetSubmission>,
+) {
+    let mut assets: Map<Bytes, Asset> = match env.storage().get(&CREATOR_ASSETS_KEY) {
+        Some(assets) => assets.unwrap(),
+        None => map![env],
+    };
+    let creator = get_creator(env);
+    for submission in submissions.iter() {
+        let (id, submission) = submission.unwrap();
+        if let Some(asset) = assets.get(id.clone()) {
+            if asset.unwrap().is_locked() {
+                panic_with_error!(env, ContractError::AssetImmutable);
+            }
+        }
+        if submission.metadata.title.is_empty() || submission.metadata.file_size == 0 {
+            panic_with_error!(env, ContractError::InvalidAssetMetadata);
+        }
+        let parent_hash = resolve_parent(env, &assets, submission.parent.clone());
+        register_work(env, &submission.content_hash, &creator, &parent_hash);
+        record_asset_event(
+            env,
+            &id,
+            AssetAction::Submitted(submission.content_hash.clone()),
+            submitter,
+        );
+        clear_review_votes(env, &id);
+        let asset = Asset::new(submission, env.ledger().timestamp());
         assets.set(id, asset);
     }
     write_assets(env, &assets)
 }
 
-pub(crate) fn approve_asset(env: &Env, assets_ids: Vec<Bytes>, date: &u64) {
+/// Returns the content hash of the approved parent asset, if the asset derives from one
+fn resolve_parent(
+    env: &Env,
+    assets: &Map<Bytes, Asset>,
+    parent: AssetReference,
+) -> Option<BytesN<32>> {
+    let parent_asset = match parent {
+        AssetReference::Original => return None,
+        AssetReference::Local(asset_id) => assets.get(asset_id).map(|asset| asset.unwrap()),
+        AssetReference::External(contract_id, asset_id) => {
+            PaymentContractClient::new(env, &contract_id)
+                .get_submitted_assets()
+                .get(asset_id)
+                .map(|asset| asset.unwrap())
+        }
+        AssetReference::ContentHash(content_hash) => {
+            if !is_registered_work(env, &content_hash) {
+                panic_with_error!(env, ContractError::InvalidParentAsset);
+            }
+            return Some(content_hash);
+        }
+    };
+    match parent_asset {
+        Some(asset) if asset.is_locked() => Some(asset.content_hash),
+        _ => panic_with_error!(env, ContractError::InvalidParentAsset),
+    }
+}
+
+pub(crate) fn update_asset_url(env: &Env, submitter: &Address, asset_id: Bytes, asset_url: Bytes) {
+    check_if_has_assets(env);
+    let mut assets: Map<Bytes, Asset> = read_assets(env);
+    let mut asset = match assets.get(asset_id.clone()) {
+        Some(asset) => asset.unwrap(),
+        None => panic_with_error!(env, ContractError::AssetsNotFound),
+    };
+    record_asset_event(
+        env,
+        &asset_id,
+        AssetAction::UrlUpdated(asset_url.clone()),
+        submitter,
+    );
+    asset.asset_url = asset_url;
+    assets.set(asset_id, asset);
+    write_assets(env, &assets)
+}
+
+pub(crate) fn approve_asset(env: &Env, approved_hashes: Map<Bytes, BytesN<32>>, date: &u64) {
     check_if_has_assets(env);
-    let mut assets: Map<Bytes, Asset> = env.storage().get_unchecked(&CREATOR_ASSETS_KEY).unwrap();
     let payment_time = get_payment_time(env);
-    assets_ids
-        .iter()
-        .for_each(|asset_id| change_asset_state(asset_id.unwrap(), &mut assets));
-    write_assets(env, &assets);
+    let assets: Map<Bytes, Asset> = read_assets(env);
+    for approved_hash in approved_hashes.iter() {
+        let (id, content_hash) = approved_hash.unwrap();
+        if !has_review_quorum(env, &id) {
+            panic_with_error!(env, ContractError::ReviewQuorumNotMet);
+        }
+        if let Some(asset) = assets.get(id) {
+            if asset.unwrap().content_hash != content_hash {
+                panic_with_error!(env, ContractError::ContentHashMismatch);
+            }
+        }
+    }
+    update_assets_state(
+        env,
+        &approved_hashes.keys(),
+        AssetState::Approved,
+        &get_contract_manager_address(env),
+    );
     if payment_time == 0 {
         execute_payment(env, date, &Option::None)
     }
 }
 
+pub(crate) fn reject_asset(env: &Env, assets_ids: Vec<Bytes>) {
+    check_if_has_assets(env);
+    update_assets_state(
+        env,
+        &assets_ids,
+        AssetState::Rejected,
+        &get_contract_manager_address(env),
+    );
+}
+
+pub(crate) fn update_assets_state(
+    env: &Env,
+    assets_ids: &Vec<Bytes>,
+    state: AssetState,
+    actor: &Address,
+) {
+    let mut assets: Map<Bytes, Asset> = read_assets(env);
+    assets_ids.iter().for_each(|asset_id| {
+        let asset_id = asset_id.unwrap();
+        if change_asset_state(asset_id.clone(), &mut assets, &state) {
+            if let Some(action) = state_action(&state) {
+                record_asset_event(env, &asset_id, action, actor);
+            }
+        }
+    });
+    write_assets(env, &assets);
+}
+
 pub(crate) fn read_assets(env: &Env) -> Map<Bytes, Asset> {
     env.storage().get(&CREATOR_ASSETS_KEY).unwrap().unwrap()
 }
@@ -65,16 +277,25 @@ pub(crate) fn write_assets(env: &Env, assets: &Map<Bytes, Asset>) {
     env.storage().set(&CREATOR_ASSETS_KEY, assets)
 }
 
+pub(crate) fn has_assets(env: &Env) -> bool {
+    env.storage().has(&CREATOR_ASSETS_KEY)
+}
+
 pub(crate) fn check_if_has_assets(env: &Env) {
-    if !env.storage().has(&CREATOR_ASSETS_KEY) {
+    if !has_assets(env) {
         panic_with_error!(env, ContractError::AssetsNotFound);
     }
 }
 
-fn change_asset_state(asset_id: Bytes, assets: &mut Map<Bytes, Asset>) {
+fn change_asset_state(asset_id: Bytes, assets: &mut Map<Bytes, Asset>, state: &AssetState) -> bool {
     if let Some(asset) = assets.get(asset_id.clone()) {
         let mut asset = asset.unwrap();
-        asset.state = AssetState::Approved;
-        assets.set(asset_id, asset)
+        if asset.state == AssetState::Paid || asset.state == AssetState::PaymentPending {
+            return false;
+        }
+        asset.state = state.clone();
+        assets.set(asset_id, asset);
+        return true;
     }
+    false
 }
\ No newline at end of file
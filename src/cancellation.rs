@@ -0,0 +1,56 @@
+//! Module Cancellation
+//!
+//! Module that lets the company cancel a signed contract, paying the creator the approved assets
+//! and the agreed kill fee for the work in progress. The escrow and the pending payouts are refunded
+//! first, so their assets are paid along with the rest.
+use crate::{
+    history::{record_contract_event, ContractAction},
+    metadata::{get_total_paid, set_contract_state},
+    payment::{pay_approved_assets, pay_from_company, refund_escrow},
+    payment_contract_info::{get_contract_info, get_contract_manager_address, KillFee},
+    settlement::drop_settlement,
+    storage_types::ContractState,
+};
+use soroban_sdk::{unwrap::UnwrapOptimized, vec, Env};
+
+const MAX_KILL_FEE_RATE: u32 = 10_000;
+
+pub(crate) fn is_valid_kill_fee(kill_fee: &KillFee) -> bool {
+    match kill_fee {
+        KillFee::None => true,
+        KillFee::Percentage(rate) => *rate <= MAX_KILL_FEE_RATE,
+        KillFee::Flat(amount) => *amount >= 0,
+    }
+}
+
+fn calculate_kill_fee(env: &Env) -> i128 {
+    let contract_info = get_contract_info(env);
+    match contract_info.kill_fee {
+        KillFee::None => 0,
+        KillFee::Percentage(rate) => {
+            let remaining_budget = (contract_info.budget - get_total_paid(env)).max(0);
+            remaining_budget
+                .checked_mul(rate as i128)
+                .unwrap_optimized()
+                / MAX_KILL_FEE_RATE as i128
+        }
+        KillFee::Flat(amount) => amount,
+    }
+}
+
+pub(crate) fn cancel_contract(env: &Env) {
+    let contract_manager_address = get_contract_manager_address(env);
+    drop_settlement(env);
+    refund_escrow(env, &contract_manager_address);
+    pay_approved_assets(env);
+    let kill_fee = calculate_kill_fee(env);
+    if kill_fee > 0 {
+        pay_from_company(env, &kill_fee, &vec![env]);
+    }
+    record_contract_event(
+        env,
+        ContractAction::Cancelled(kill_fee),
+        &contract_manager_address,
+    );
+    set_contract_state(env, &ContractState::Cancelled)
+}
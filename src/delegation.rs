@@ -0,0 +1,81 @@
+use crate::{error::ContractError, payment_contract_info::get_creator, storage_types::DataKey};
+use soroban_sdk::{contracttype, panic_with_error, unwrap::UnwrapOptimized, Address, Env};
+
+const DELEGATION_KEY: DataKey = DataKey::Delegation;
+const MAX_COMMISSION_RATE: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum DelegationScope {
+    /// The delegate can only sign the contract
+    Sign,
+    /// The delegate can only submit assets
+    Submit,
+    /// The delegate can sign the contract and submit assets
+    SignAndSubmit,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Authorization given by the creator to an agent or agency to act on their behalf
+pub struct Delegation {
+    /// The address of the agent or agency
+    pub delegate: Address,
+    /// The actions the delegate is allowed to perform
+    pub scope: DelegationScope,
+    /// The last date on which the delegate can act for the creator
+    pub expiry: u64,
+    /// The share of every payout that goes to the delegate, in basis points
+    pub commission_rate: u32,
+}
+
+impl DelegationScope {
+    fn allows(&self, action: &DelegationScope) -> bool {
+        *self == DelegationScope::SignAndSubmit || self == action
+    }
+}
+
+pub(crate) fn write_delegation(env: &Env, delegation: &Delegation) {
+    if delegation.commission_rate > MAX_COMMISSION_RATE {
+        panic_with_error!(env, ContractError::InvalidDelegation);
+    }
+    env.storage().set(&DELEGATION_KEY, delegation)
+}
+
+pub(crate) fn remove_delegation(env: &Env) {
+    env.storage().remove(&DELEGATION_KEY)
+}
+
+pub(crate) fn read_delegation(env: &Env) -> Option<Delegation> {
+    env.storage()
+        .get(&DELEGATION_KEY)
+        .map(|delegation| delegation.unwrap())
+}
+
+/// Reads the delegation, ignoring it once its expiry is behind the ledger timestamp
+fn read_active_delegation(env: &Env) -> Option<Delegation> {
+    read_delegation(env).filter(|delegation| env.ledger().timestamp() <= delegation.expiry)
+}
+
+pub(crate) fn require_creator_or_delegate(env: &Env, caller: &Address, action: DelegationScope) {
+    if *caller != get_creator(env) {
+        match read_active_delegation(env) {
+            Some(delegation)
+                if delegation.delegate == *caller && delegation.scope.allows(&action) => {}
+            _ => panic_with_error!(env, ContractError::NotAuthorizedDelegate),
+        }
+    }
+    caller.require_auth();
+}
+
+pub(crate) fn calculate_commission(env: &Env, payment_amount: &i128) -> Option<(Address, i128)> {
+    let delegation = read_active_delegation(env)?;
+    if delegation.commission_rate == 0 {
+        return None;
+    }
+    let commission = payment_amount
+        .checked_mul(delegation.commission_rate as i128)
+        .unwrap_optimized()
+        / MAX_COMMISSION_RATE as i128;
+    Some((delegation.delegate, commission))
+}
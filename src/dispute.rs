@@ -0,0 +1,445 @@
+use crate::{
+    asset::{check_if_has_assets, read_assets, update_assets_state, AssetState},
+    error::ContractError,
+    payment::{execute_partial_payment, execute_payment, transfer},
+    payment_contract_info::{
+        get_arbitration_panel, get_contract_manager_address, get_creator,
+        get_dispute_response_window, get_payment_time, ArbitrationPanel,
+    },
+    registry::is_flagged_work,
+    storage_types::DataKey,
+};
+use soroban_sdk::{
+    contracttype, map, panic_with_error, unwrap::UnwrapOptimized, vec, Address, Bytes, BytesN, Env,
+    Map, Vec,
+};
+
+const NEXT_DISPUTE_ID_KEY: DataKey = DataKey::NextDisputeId;
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Ruling {
+    /// The arbitration panel hasn't ruled on the dispute yet
+    Pending,
+    /// The disputed assets are treated as approved
+    ForceApproval,
+    /// The disputed assets stay rejected
+    UpholdRejection,
+    /// The creator is paid the given amount for the disputed assets that are still unpaid
+    PartialPayment(i128),
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum DisputeState {
+    Open,
+    /// The ruling is recorded and waits for the company to fund the payment it requires
+    Ruled,
+    Resolved,
+    /// The voting period ended without a majority ruling
+    Dismissed,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Dispute raised by one of the parties over a set of submitted assets
+pub struct Dispute {
+    /// The party that opened the dispute
+    pub claimant: Address,
+    /// The assets whose payouts are frozen while the dispute is open
+    pub asset_ids: Vec<Bytes>,
+    /// Hash of the evidence provided by the claimant
+    pub evidence_hash: BytesN<32>,
+    /// The ledger timestamp at which the dispute was opened
+    pub opening_date: u64,
+    /// The last ledger timestamp at which the arbitration panel can vote
+    pub voting_deadline: u64,
+    pub state: DisputeState,
+    /// The votes cast by the arbitration panel
+    pub votes: Map<Address, Ruling>,
+    /// The deposit made by the claimant to fund the arbitration fee
+    pub claimant_deposit: i128,
+    /// The deposit made by the respondent to fund the arbitration fee
+    pub respondent_deposit: i128,
+    /// The panel ruling once the dispute is ruled
+    pub ruling: Ruling,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Evidence appended by one of the parties to the open dispute
+pub struct Evidence {
+    /// Hash of the evidence content
+    pub content_hash: BytesN<32>,
+    /// Location where the evidence can be fetched
+    pub uri: Bytes,
+    /// The ledger timestamp at which the evidence was submitted
+    pub timestamp: u64,
+    /// The party that submitted the evidence
+    pub author: Address,
+}
+
+pub(crate) fn read_dispute(env: &Env, dispute_id: u32) -> Option<Dispute> {
+    env.storage()
+        .get(&DataKey::Dispute(dispute_id))
+        .map(|dispute| dispute.unwrap())
+}
+
+fn write_dispute(env: &Env, dispute_id: u32, dispute: &Dispute) {
+    env.storage().set(&DataKey::Dispute(dispute_id), dispute)
+}
+
+fn read_next_dispute_id(env: &Env) -> u32 {
+    match env.storage().get(&NEXT_DISPUTE_ID_KEY) {
+        Some(id) => id.unwrap(),
+        None => 0,
+    }
+}
+
+/// Returns the last opened dispute along with its id, as only one dispute can be open at a time
+fn read_latest_dispute(env: &Env) -> Option<(u32, Dispute)> {
+    match read_next_dispute_id(env) {
+        0 => None,
+        next_id => read_dispute(env, next_id - 1).map(|dispute| (next_id - 1, dispute)),
+    }
+}
+
+pub(crate) fn read_evidence(env: &Env, dispute_id: u32) -> Vec<Evidence> {
+    match env.storage().get(&DataKey::DisputeEvidence(dispute_id)) {
+        Some(evidence) => evidence.unwrap(),
+        None => vec![env],
+    }
+}
+
+fn write_evidence(env: &Env, dispute_id: u32, evidence: &Vec<Evidence>) {
+    env.storage()
+        .set(&DataKey::DisputeEvidence(dispute_id), evidence)
+}
+
+fn read_panel(env: &Env) -> ArbitrationPanel {
+    let panel = get_arbitration_panel(env);
+    if panel.arbitrators.is_empty() {
+        panic_with_error!(env, ContractError::NoArbitrator);
+    }
+    panel
+}
+
+pub(crate) fn require_panel_member(env: &Env, arbitrator: &Address) {
+    if !read_panel(env).arbitrators.contains(arbitrator) {
+        panic_with_error!(env, ContractError::NotPanelMember);
+    }
+    arbitrator.require_auth();
+}
+
+fn read_open_dispute(env: &Env) -> (u32, Dispute) {
+    match read_latest_dispute(env) {
+        Some((dispute_id, dispute)) if dispute.state == DisputeState::Open => (dispute_id, dispute),
+        _ => panic_with_error!(env, ContractError::NoOpenDispute),
+    }
+}
+
+fn check_contract_party(env: &Env, address: &Address) {
+    if *address != get_creator(env) && *address != get_contract_manager_address(env) {
+        panic_with_error!(env, ContractError::NotContractParty);
+    }
+}
+
+fn calculate_deposit(panel: &ArbitrationPanel) -> i128 {
+    panel
+        .arbitration_fee
+        .checked_mul(panel.arbitrators.len() as i128)
+        .unwrap_optimized()
+}
+
+pub(crate) fn open_dispute(
+    env: &Env,
+    claimant: &Address,
+    asset_ids: Vec<Bytes>,
+    evidence_hash: BytesN<32>,
+) -> u32 {
+    let panel = read_panel(env);
+    check_contract_party(env, claimant);
+    if let Some((_, dispute)) = read_latest_dispute(env) {
+        if dispute.state == DisputeState::Open {
+            panic_with_error!(env, ContractError::DisputeAlreadyOpen);
+        }
+    }
+    check_if_has_assets(env);
+    let assets = read_assets(env);
+    for asset_id in asset_ids.iter() {
+        if !assets.contains_key(asset_id.unwrap()) {
+            panic_with_error!(env, ContractError::AssetsNotFound);
+        }
+    }
+    let deposit = calculate_deposit(&panel);
+    if deposit > 0 {
+        transfer(env, claimant, &env.current_contract_address(), &deposit);
+    }
+    let opening_date = env.ledger().timestamp();
+    let dispute_id = read_next_dispute_id(env);
+    env.storage().set(&NEXT_DISPUTE_ID_KEY, &(dispute_id + 1));
+    write_evidence(
+        env,
+        dispute_id,
+        &vec![
+            env,
+            Evidence {
+                content_hash: evidence_hash.clone(),
+                uri: Bytes::new(env),
+                timestamp: opening_date,
+                author: claimant.clone(),
+            },
+        ],
+    );
+    write_dispute(
+        env,
+        dispute_id,
+        &Dispute {
+            claimant: claimant.clone(),
+            asset_ids,
+            evidence_hash,
+            opening_date,
+            voting_deadline: opening_date
+                .saturating_add(get_dispute_response_window(env))
+                .saturating_add(panel.voting_period),
+            state: DisputeState::Open,
+            votes: map![env],
+            claimant_deposit: deposit,
+            respondent_deposit: 0,
+            ruling: Ruling::Pending,
+        },
+    );
+    dispute_id
+}
+
+pub(crate) fn deposit_arbitration_fee(env: &Env, respondent: &Address) {
+    check_contract_party(env, respondent);
+    let (dispute_id, mut dispute) = read_open_dispute(env);
+    if *respondent == dispute.claimant || dispute.respondent_deposit > 0 {
+        panic_with_error!(env, ContractError::DepositAlreadyMade);
+    }
+    let deposit = calculate_deposit(&read_panel(env));
+    transfer(env, respondent, &env.current_contract_address(), &deposit);
+    dispute.respondent_deposit = deposit;
+    write_dispute(env, dispute_id, &dispute)
+}
+
+pub(crate) fn add_evidence(env: &Env, author: &Address, content_hash: BytesN<32>, uri: Bytes) {
+    check_contract_party(env, author);
+    let (dispute_id, dispute) = read_open_dispute(env);
+    let response_window = get_dispute_response_window(env);
+    let date = env.ledger().timestamp();
+    if response_window != 0 && date > dispute.opening_date.saturating_add(response_window) {
+        panic_with_error!(env, ContractError::ResponseWindowClosed);
+    }
+    let mut evidence = read_evidence(env, dispute_id);
+    evidence.push_back(Evidence {
+        content_hash,
+        uri,
+        timestamp: date,
+        author: author.clone(),
+    });
+    write_evidence(env, dispute_id, &evidence)
+}
+
+pub(crate) fn resolve_dispute_by_default(env: &Env) {
+    let (dispute_id, dispute) = read_open_dispute(env);
+    let response_window = get_dispute_response_window(env);
+    if response_window == 0
+        || env.ledger().timestamp() <= dispute.opening_date.saturating_add(response_window)
+    {
+        panic_with_error!(env, ContractError::WindowOpen);
+    }
+    for evidence in read_evidence(env, dispute_id).iter() {
+        if evidence.unwrap().author != dispute.claimant {
+            panic_with_error!(env, ContractError::DisputeAnswered);
+        }
+    }
+    let ruling = if dispute.claimant == get_creator(env) {
+        Ruling::ForceApproval
+    } else {
+        Ruling::UpholdRejection
+    };
+    close_dispute(env, dispute_id, dispute, Some(ruling))
+}
+
+pub(crate) fn vote_on_dispute(env: &Env, arbitrator: &Address, ruling: Ruling) {
+    let (dispute_id, mut dispute) = read_open_dispute(env);
+    match ruling {
+        Ruling::Pending => panic_with_error!(env, ContractError::InvalidRuling),
+        Ruling::PartialPayment(amount) if amount <= 0 => {
+            panic_with_error!(env, ContractError::InvalidRuling)
+        }
+        _ => {}
+    }
+    if env.ledger().timestamp() > dispute.voting_deadline {
+        panic_with_error!(env, ContractError::VotingClosed);
+    }
+    if dispute.votes.contains_key(arbitrator.clone()) {
+        panic_with_error!(env, ContractError::AlreadyVoted);
+    }
+    dispute.votes.set(arbitrator.clone(), ruling.clone());
+    let panel_size = read_panel(env).arbitrators.len();
+    if count_votes(&dispute.votes, &ruling) * 2 > panel_size {
+        close_dispute(env, dispute_id, dispute, Some(ruling))
+    } else {
+        write_dispute(env, dispute_id, &dispute)
+    }
+}
+
+pub(crate) fn close_dispute_vote(env: &Env) {
+    let (dispute_id, dispute) = read_open_dispute(env);
+    if env.ledger().timestamp() <= dispute.voting_deadline {
+        panic_with_error!(env, ContractError::WindowOpen);
+    }
+    let mut leading_ruling: Option<Ruling> = None;
+    let mut leading_votes: u32 = 0;
+    for vote in dispute.votes.values().iter() {
+        let ruling = vote.unwrap();
+        let votes = count_votes(&dispute.votes, &ruling);
+        if votes > leading_votes {
+            leading_votes = votes;
+            leading_ruling = Some(ruling);
+        } else if votes == leading_votes && leading_ruling.as_ref() != Some(&ruling) {
+            leading_ruling = None;
+        }
+    }
+    close_dispute(env, dispute_id, dispute, leading_ruling)
+}
+
+fn count_votes(votes: &Map<Address, Ruling>, ruling: &Ruling) -> u32 {
+    let mut count = 0;
+    for vote in votes.values().iter() {
+        if vote.unwrap() == *ruling {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Records the ruling, settles the deposits and applies the ruling to the disputed assets. Rulings
+/// that require a payment wait in the `Ruled` state until `enforce_ruling` funds them, so closing
+/// the dispute never depends on the company
+fn close_dispute(env: &Env, dispute_id: u32, mut dispute: Dispute, ruling: Option<Ruling>) {
+    dispute.state = match ruling.clone() {
+        None => DisputeState::Dismissed,
+        Some(Ruling::ForceApproval) => {
+            update_assets_state(
+                env,
+                &dispute.asset_ids,
+                AssetState::Approved,
+                &env.current_contract_address(),
+            );
+            if get_payment_time(env) == 0 {
+                DisputeState::Ruled
+            } else {
+                DisputeState::Resolved
+            }
+        }
+        Some(Ruling::UpholdRejection) => {
+            update_assets_state(
+                env,
+                &dispute.asset_ids,
+                AssetState::Rejected,
+                &env.current_contract_address(),
+            );
+            DisputeState::Resolved
+        }
+        Some(Ruling::PartialPayment(_)) => DisputeState::Ruled,
+        Some(Ruling::Pending) => panic_with_error!(env, ContractError::InvalidRuling),
+    };
+    dispute.ruling = ruling.unwrap_or(Ruling::Pending);
+    write_dispute(env, dispute_id, &dispute);
+    settle_deposits(env, &dispute)
+}
+
+/// Pays the ruling of the dispute from the company funds
+pub(crate) fn enforce_ruling(env: &Env, dispute_id: u32) {
+    let mut dispute = match read_dispute(env, dispute_id) {
+        Some(dispute) if dispute.state == DisputeState::Ruled => dispute,
+        _ => panic_with_error!(env, ContractError::NoRulingToEnforce),
+    };
+    dispute.state = DisputeState::Resolved;
+    write_dispute(env, dispute_id, &dispute);
+
+    match dispute.ruling {
+        Ruling::ForceApproval => execute_payment(env, &env.ledger().timestamp(), &Option::None),
+        Ruling::PartialPayment(amount) => execute_partial_payment(env, &amount, &dispute.asset_ids),
+        _ => {}
+    }
+}
+
+/// Pays every arbitrator that voted from the losing side deposit, falling back to the winning
+/// side deposit when the losing side never made one, and refunds what is left to each party
+fn settle_deposits(env: &Env, dispute: &Dispute) {
+    let creator = get_creator(env);
+    let respondent = if dispute.claimant == creator {
+        get_contract_manager_address(env)
+    } else {
+        creator.clone()
+    };
+    let claimant_wins = match dispute.ruling {
+        Ruling::Pending => false,
+        Ruling::UpholdRejection => dispute.claimant != creator,
+        _ => dispute.claimant == creator,
+    };
+    let (winner, mut winner_refund, loser, mut loser_refund) = if claimant_wins {
+        (
+            dispute.claimant.clone(),
+            dispute.claimant_deposit,
+            respondent,
+            dispute.respondent_deposit,
+        )
+    } else {
+        (
+            respondent,
+            dispute.respondent_deposit,
+            dispute.claimant.clone(),
+            dispute.claimant_deposit,
+        )
+    };
+
+    let arbitration_fee = read_panel(env).arbitration_fee;
+    let total_fee = arbitration_fee
+        .checked_mul(dispute.votes.len() as i128)
+        .unwrap_optimized();
+    if total_fee > 0 {
+        if loser_refund >= total_fee {
+            loser_refund -= total_fee;
+        } else {
+            winner_refund -= total_fee;
+        }
+        for arbitrator in dispute.votes.keys().iter() {
+            transfer(
+                env,
+                &env.current_contract_address(),
+                &arbitrator.unwrap(),
+                &arbitration_fee,
+            );
+        }
+    }
+    if winner_refund > 0 {
+        transfer(
+            env,
+            &env.current_contract_address(),
+            &winner,
+            &winner_refund,
+        );
+    }
+    if loser_refund > 0 {
+        transfer(env, &env.current_contract_address(), &loser, &loser_refund);
+    }
+}
+
+pub(crate) fn is_asset_frozen(env: &Env, asset_id: &Bytes) -> bool {
+    if let Some((_, dispute)) = read_latest_dispute(env) {
+        if dispute.state == DisputeState::Open && dispute.asset_ids.contains(asset_id) {
+            return true;
+        }
+    }
+    // Assets flagged by an upheld infringement claim are frozen too
+    match read_assets(env).get(asset_id.clone()) {
+        Some(asset) => is_flagged_work(env, &asset.unwrap().content_hash),
+        None => false,
+    }
+}
@@ -16,4 +16,90 @@ pub enum ContractError {
     NoApprovedAssets = 5,
     /// Error that indicates the contract wasn't already initialized
     NotInitialized = 6,
+    /// Error that indicates the payout policy has an invalid threshold or number of approvals
+    InvalidPayoutPolicy = 7,
+    /// Error that indicates the address isn't part of the payout signers group
+    NotPayoutSigner = 8,
+    /// Error that indicates there is no pending payout with the given id
+    PayoutNotFound = 9,
+    /// Error that indicates the signer already approved the pending payout
+    PayoutAlreadyApproved = 10,
+    /// Error that indicates the delegation commission is above 100%
+    InvalidDelegation = 11,
+    /// Error that indicates the caller isn't the creator nor a delegate allowed to act for them
+    NotAuthorizedDelegate = 12,
+    /// Error that indicates the contract doesn't name an arbitration panel to resolve disputes
+    NoArbitrator = 13,
+    /// Error that indicates the caller isn't the creator nor the contract manager
+    NotContractParty = 14,
+    /// Error that indicates there is already an open dispute in the contract
+    DisputeAlreadyOpen = 15,
+    /// Error that indicates there is no open dispute to act on
+    NoOpenDispute = 16,
+    /// Error that indicates the payout includes assets frozen by an open dispute
+    AssetsFrozen = 17,
+    /// Error that indicates the ruling is pending or pays a non-positive amount
+    InvalidRuling = 18,
+    /// Error that indicates there is no ruling waiting for its payment
+    NoRulingToEnforce = 19,
+    /// Error that indicates the time to submit evidence for the dispute is over
+    ResponseWindowClosed = 20,
+    /// Error that indicates the action can't be taken until its time window is over
+    WindowOpen = 21,
+    /// Error that indicates the respondent submitted evidence so the dispute can't be ruled by default
+    DisputeAnswered = 22,
+    /// Error that indicates the address isn't part of the arbitration panel
+    NotPanelMember = 23,
+    /// Error that indicates the arbitrator already voted on the dispute
+    AlreadyVoted = 24,
+    /// Error that indicates the voting period of the dispute is over
+    VotingClosed = 25,
+    /// Error that indicates the party already made its deposit for the dispute
+    DepositAlreadyMade = 26,
+    /// Error that indicates the asset was already approved so its submission can't be replaced
+    AssetImmutable = 27,
+    /// Error that indicates the approved content hash doesn't match the submitted one
+    ContentHashMismatch = 28,
+    /// Error that indicates the submitted asset metadata is missing its title or file size
+    InvalidAssetMetadata = 29,
+    /// Error that indicates the asset has no rights record because it wasn't paid yet
+    RightsRecordNotFound = 30,
+    /// Error that indicates a licensing contract doesn't define its license terms
+    MissingLicenseTerms = 31,
+    /// Error that indicates there is no license with the given id
+    LicenseNotFound = 32,
+    /// Error that indicates the address doesn't hold the license
+    NotLicenseHolder = 33,
+    /// Error that indicates the license terms don't allow sublicenses
+    NotSublicensable = 34,
+    /// Error that indicates the license is no longer valid
+    LicenseExpired = 35,
+    /// Error that indicates the license is perpetual or a sublicense so it can't be renewed
+    NotRenewable = 36,
+    /// Error that indicates the parent of a derivative asset doesn't exist or isn't approved
+    InvalidParentAsset = 37,
+    /// Error that indicates the signed terms hash doesn't match the current contract terms
+    TermsHashMismatch = 38,
+    /// Error that indicates there is no amendment waiting for an answer
+    NoPendingAmendment = 39,
+    /// Error that indicates another amendment is still waiting for an answer
+    AmendmentPending = 40,
+    /// Error that indicates the proposal can only be answered by the other party of the contract
+    NotCounterparty = 41,
+    /// Error that indicates the offer expired before being signed
+    OfferExpired = 42,
+    /// Error that indicates there is no counter-offer waiting for an answer
+    NoCounterOffer = 43,
+    /// Error that indicates the kill fee percentage is over 100% or the flat amount is negative
+    InvalidKillFee = 44,
+    /// Error that indicates there is no settlement waiting for an answer
+    NoPendingSettlement = 45,
+    /// Error that indicates the settlement amount is negative or differs from the proposed one
+    SettlementMismatch = 46,
+    /// Error that indicates the review policy has no reviewers or an invalid number of approvals
+    InvalidReviewPolicy = 47,
+    /// Error that indicates the address isn't one of the reviewers of the contract
+    NotReviewer = 48,
+    /// Error that indicates the asset doesn't have enough reviewer approvals yet
+    ReviewQuorumNotMet = 49,
 }
\ No newline at end of file
@@ -0,0 +1,108 @@
+//! Module History
+//!
+//! Module that keeps an append-only log of every change made to each submitted asset, from its
+//! submission to the transfer of its rights, and of the changes that end the contract.
+use crate::{asset::AssetState, storage_types::DataKey};
+use soroban_sdk::{contracttype, vec, Address, Bytes, BytesN, Env, Vec};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum AssetAction {
+    /// The asset was submitted or resubmitted with the given content hash
+    Submitted(BytesN<32>),
+    /// The asset url was replaced with the given one
+    UrlUpdated(Bytes),
+    Approved,
+    Rejected,
+    /// The payment was queued until the payout signers approve it
+    PaymentPending,
+    Paid,
+    /// The asset rights were assigned to the given address
+    RightsAssigned(Address),
+    /// The license with the given id was granted over the asset
+    LicenseGranted(u64),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Entry of the history of an asset
+pub struct AssetEvent {
+    pub action: AssetAction,
+    /// The address that triggered the change
+    pub actor: Address,
+    /// Ledger timestamp of the change
+    pub timestamp: u64,
+}
+
+pub(crate) fn read_asset_history(env: &Env, asset_id: Bytes) -> Vec<AssetEvent> {
+    match env.storage().get(&DataKey::AssetHistory(asset_id)) {
+        Some(history) => history.unwrap(),
+        None => vec![env],
+    }
+}
+
+pub(crate) fn record_asset_event(
+    env: &Env,
+    asset_id: &Bytes,
+    action: AssetAction,
+    actor: &Address,
+) {
+    let mut history = read_asset_history(env, asset_id.clone());
+    history.push_back(AssetEvent {
+        action,
+        actor: actor.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage()
+        .set(&DataKey::AssetHistory(asset_id.clone()), &history)
+}
+
+/// The action that describes an asset moving to the given state
+pub(crate) fn state_action(state: &AssetState) -> Option<AssetAction> {
+    match state {
+        AssetState::InReview => None,
+        AssetState::Approved => Some(AssetAction::Approved),
+        AssetState::Rejected => Some(AssetAction::Rejected),
+        AssetState::PaymentPending => Some(AssetAction::PaymentPending),
+        AssetState::Paid => Some(AssetAction::Paid),
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ContractAction {
+    /// The company cancelled the contract paying the given kill fee
+    Cancelled(i128),
+    /// A party proposed to terminate the contract for the given settlement amount
+    SettlementProposed(i128),
+    /// The parties terminated the contract with the given settlement amount
+    Terminated(i128),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Entry of the history of the contract
+pub struct ContractEvent {
+    pub action: ContractAction,
+    /// The address that triggered the change
+    pub actor: Address,
+    /// Ledger timestamp of the change
+    pub timestamp: u64,
+}
+
+pub(crate) fn read_contract_history(env: &Env) -> Vec<ContractEvent> {
+    match env.storage().get(&DataKey::ContractHistory) {
+        Some(history) => history.unwrap(),
+        None => vec![env],
+    }
+}
+
+pub(crate) fn record_contract_event(env: &Env, action: ContractAction, actor: &Address) {
+    let mut history = read_contract_history(env);
+    history.push_back(ContractEvent {
+        action,
+        actor: actor.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().set(&DataKey::ContractHistory, &history)
+}
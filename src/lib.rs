@@ -1,18 +1,44 @@
 #![no_std]
 
+mod amendment;
 mod asset;
+mod cancellation;
+mod delegation;
+mod dispute;
 mod error;
+mod history;
+mod license;
 mod metadata;
+mod multisig;
+mod offer;
 mod payment;
 mod payment_contract_info;
+mod project;
+mod quorum;
+mod registry;
+mod review;
+mod rights;
+mod settlement;
 mod storage_types;
+mod template;
 
-use asset::{check_if_has_assets, Asset};
+use amendment::{Amendment, TermChange};
+use asset::{check_if_has_assets, Asset, AssetSubmission};
+use delegation::{Delegation, DelegationScope};
+use dispute::{Dispute, Evidence, Ruling};
 use error::ContractError;
+use history::{AssetEvent, ContractEvent};
+use license::License;
 use metadata::{is_contract_active, is_contract_with_state};
-use payment_contract_info::{has_contact_info, PaymentContractInfo};
-use soroban_sdk::{contractimpl, panic_with_error, Address, Bytes, Env, Map, Vec};
+use multisig::{PayoutPolicy, PendingPayout};
+use offer::{CounterOffer, CounterOfferTerms};
+use payment_contract_info::{has_contact_info, ContractType, OfferedLicense, PaymentContractInfo};
+use quorum::{ReviewPolicy, ReviewVote};
+use rights::RightsRecord;
+use settlement::Settlement;
+use soroban_sdk::{contractimpl, panic_with_error, Address, Bytes, BytesN, Env, Map, Vec};
 use storage_types::ContractState;
+use template::{ContractDeal, TemplateReference};
 
 pub struct PaymentContract;
 
@@ -22,47 +48,354 @@ impl PaymentContract {
         if has_contact_info(&env) {
             panic_with_error!(env, ContractError::AlreadyInitialized);
         }
+        if contract_info.contract_type == ContractType::Licensing
+            && contract_info.license_terms == OfferedLicense::None
+        {
+            panic_with_error!(env, ContractError::MissingLicenseTerms);
+        }
+        if !cancellation::is_valid_kill_fee(&contract_info.kill_fee) {
+            panic_with_error!(env, ContractError::InvalidKillFee);
+        }
         payment_contract_info::write_contract_info(&env, &contract_info);
         payment_contract_info::write_creator(&env, &creator)
     }
 
+    pub fn initialize_from_template(
+        env: Env,
+        template: TemplateReference,
+        deal: ContractDeal,
+        creator: Address,
+    ) {
+        let contract_info = template::build_contract_info(&env, &template, deal);
+        Self::initialize(env.clone(), contract_info, creator);
+        template::write_template_reference(&env, &template)
+    }
+
+    pub fn get_template_reference(env: Env) -> Option<TemplateReference> {
+        template::read_template_reference(&env)
+    }
+
     pub fn update_creator(env: Env, creator: Address) {
         payment_contract_info::get_contract_manager_address(&env).require_auth();
+        delegation::remove_delegation(&env);
         payment_contract_info::write_creator(&env, &creator)
     }
 
-    pub fn sign_contract(env: Env, date: u64) {
+    pub fn authorize_delegate(env: Env, delegation: Delegation) {
+        payment_contract_info::get_creator(&env).require_auth();
+        delegation::write_delegation(&env, &delegation)
+    }
+
+    pub fn revoke_delegate(env: Env) {
+        payment_contract_info::get_creator(&env).require_auth();
+        delegation::remove_delegation(&env)
+    }
+
+    pub fn get_delegation(env: Env) -> Option<Delegation> {
+        delegation::read_delegation(&env)
+    }
+
+    pub fn sign_contract(env: Env, signer: Address, terms_hash: BytesN<32>, date: u64) {
+        if is_contract_with_state(&env) {
+            panic_with_error!(env, ContractError::AlreadyInProgress)
+        }
+        delegation::require_creator_or_delegate(&env, &signer, DelegationScope::Sign);
+        offer::check_offer_expiry(&env);
+        if terms_hash != payment_contract_info::get_terms_hash(&env) {
+            panic_with_error!(env, ContractError::TermsHashMismatch)
+        }
+        metadata::sign_contract(&env, &date, &terms_hash);
+    }
+
+    pub fn submit_counter_offer(env: Env, proposer: Address, terms: CounterOfferTerms) {
+        if is_contract_with_state(&env) {
+            panic_with_error!(env, ContractError::AlreadyInProgress)
+        }
+        offer::submit_counter_offer(&env, &proposer, terms)
+    }
+
+    pub fn accept_counter_offer(env: Env, date: u64) {
         if is_contract_with_state(&env) {
             panic_with_error!(env, ContractError::AlreadyInProgress)
         }
-        let creator = payment_contract_info::get_creator(&env);
-        creator.require_auth();
-        metadata::sign_contract(&env, &date);
+        payment_contract_info::get_contract_manager_address(&env).require_auth();
+        offer::accept_counter_offer(&env, &date)
+    }
+
+    pub fn reject_counter_offer(env: Env) {
+        payment_contract_info::get_contract_manager_address(&env).require_auth();
+        offer::reject_counter_offer(&env)
+    }
+
+    pub fn get_counter_offer(env: Env) -> Option<CounterOffer> {
+        offer::read_counter_offer(&env)
+    }
+
+    pub fn get_terms_hash(env: Env) -> BytesN<32> {
+        payment_contract_info::get_terms_hash(&env)
+    }
+
+    pub fn get_accepted_terms_hash(env: Env) -> Option<BytesN<32>> {
+        metadata::get_accepted_terms_hash(&env)
+    }
+
+    pub fn propose_amendment(env: Env, proposer: Address, changes: Vec<TermChange>) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        amendment::propose_amendment(&env, &proposer, changes)
     }
 
-    pub fn submit_asset(env: Env, assets: Map<Bytes, Bytes>, submission_date: u64) {
+    pub fn accept_amendment(env: Env, signer: Address, terms_hash: BytesN<32>) {
         if !is_contract_active(&env) {
             panic_with_error!(env, ContractError::ContractNotActive)
         }
-        let creator = payment_contract_info::get_creator(&env);
-        creator.require_auth();
-        asset::store_assets(&env, assets, submission_date)
+        amendment::accept_amendment(&env, &signer, terms_hash)
     }
 
-    pub fn approve_asset(env: Env, asset_ids: Vec<Bytes>, date: u64) {
+    pub fn decline_amendment(env: Env, signer: Address) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        amendment::decline_amendment(&env, &signer)
+    }
+
+    pub fn get_amendments(env: Env) -> Vec<Amendment> {
+        amendment::read_amendments(&env)
+    }
+
+    pub fn submit_asset(env: Env, submitter: Address, assets: Map<Bytes, AssetSubmission>) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        delegation::require_creator_or_delegate(&env, &submitter, DelegationScope::Submit);
+        asset::store_assets(&env, &submitter, assets)
+    }
+
+    pub fn update_asset_url(env: Env, submitter: Address, asset_id: Bytes, asset_url: Bytes) {
+        delegation::require_creator_or_delegate(&env, &submitter, DelegationScope::Submit);
+        asset::update_asset_url(&env, &submitter, asset_id, asset_url)
+    }
+
+    pub fn approve_asset(env: Env, assets: Map<Bytes, BytesN<32>>, date: u64) {
         payment_contract_info::get_contract_manager_address(&env).require_auth();
-        asset::approve_asset(&env, asset_ids, &date);
+        asset::approve_asset(&env, assets, &date);
+    }
+
+    pub fn finalize_review(env: Env, asset_ids: Vec<Bytes>) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        review::finalize_review(&env, asset_ids)
+    }
+
+    pub fn fund_escrow(env: Env, amount: i128) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        payment::fund_escrow(&env, &amount)
+    }
+
+    pub fn get_escrow(env: Env) -> i128 {
+        payment::read_escrow(&env)
+    }
+
+    pub fn reject_asset(env: Env, asset_ids: Vec<Bytes>) {
+        payment_contract_info::get_contract_manager_address(&env).require_auth();
+        asset::reject_asset(&env, asset_ids);
     }
 
     pub fn execute_payment(env: Env, date: u64, prepayment_source: Option<Address>) {
         payment::execute_payment(&env, &date, &prepayment_source)
     }
 
+    pub fn set_payout_policy(env: Env, policy: PayoutPolicy) {
+        if is_contract_with_state(&env) {
+            panic_with_error!(env, ContractError::AlreadyInProgress)
+        }
+        payment_contract_info::get_contract_manager_address(&env).require_auth();
+        multisig::write_payout_policy(&env, &policy)
+    }
+
+    pub fn set_review_policy(env: Env, policy: ReviewPolicy) {
+        if is_contract_with_state(&env) {
+            panic_with_error!(env, ContractError::AlreadyInProgress)
+        }
+        payment_contract_info::get_contract_manager_address(&env).require_auth();
+        quorum::write_review_policy(&env, &policy)
+    }
+
+    pub fn get_review_policy(env: Env) -> Option<ReviewPolicy> {
+        quorum::read_review_policy(&env)
+    }
+
+    pub fn vote_on_asset(env: Env, reviewer: Address, asset_id: Bytes, vote: ReviewVote) {
+        reviewer.require_auth();
+        check_if_has_assets(&env);
+        quorum::vote_on_asset(&env, &reviewer, asset_id, vote)
+    }
+
+    pub fn get_review_votes(env: Env, asset_id: Bytes) -> Map<Address, ReviewVote> {
+        quorum::read_review_votes(&env, asset_id)
+    }
+
+    pub fn approve_payout(env: Env, signer: Address, payout_id: u32) {
+        signer.require_auth();
+        multisig::approve_payout(&env, &signer, payout_id)
+    }
+
+    pub fn get_pending_payouts(env: Env) -> Map<u32, PendingPayout> {
+        multisig::read_pending_payouts(&env)
+    }
+
+    pub fn open_dispute(
+        env: Env,
+        claimant: Address,
+        asset_ids: Vec<Bytes>,
+        evidence_hash: BytesN<32>,
+    ) -> u32 {
+        claimant.require_auth();
+        dispute::open_dispute(&env, &claimant, asset_ids, evidence_hash)
+    }
+
+    pub fn add_evidence(env: Env, author: Address, content_hash: BytesN<32>, uri: Bytes) {
+        author.require_auth();
+        dispute::add_evidence(&env, &author, content_hash, uri)
+    }
+
+    pub fn deposit_arbitration_fee(env: Env, respondent: Address) {
+        respondent.require_auth();
+        dispute::deposit_arbitration_fee(&env, &respondent)
+    }
+
+    pub fn vote_on_dispute(env: Env, arbitrator: Address, ruling: Ruling) {
+        dispute::require_panel_member(&env, &arbitrator);
+        dispute::vote_on_dispute(&env, &arbitrator, ruling)
+    }
+
+    pub fn close_dispute_vote(env: Env) {
+        dispute::close_dispute_vote(&env)
+    }
+
+    pub fn resolve_dispute_by_default(env: Env, arbitrator: Address) {
+        dispute::require_panel_member(&env, &arbitrator);
+        dispute::resolve_dispute_by_default(&env)
+    }
+
+    pub fn enforce_ruling(env: Env, dispute_id: u32) {
+        dispute::enforce_ruling(&env, dispute_id)
+    }
+
+    pub fn get_dispute_evidence(env: Env, dispute_id: u32) -> Vec<Evidence> {
+        dispute::read_evidence(&env, dispute_id)
+    }
+
+    pub fn get_dispute(env: Env, dispute_id: u32) -> Dispute {
+        match dispute::read_dispute(&env, dispute_id) {
+            Some(dispute) => dispute,
+            None => panic_with_error!(env, ContractError::NoOpenDispute),
+        }
+    }
+
     pub fn get_submitted_assets(env: Env) -> Map<Bytes, Asset> {
         check_if_has_assets(&env);
         asset::read_assets(&env)
     }
 
+    pub fn get_asset_history(env: Env, asset_id: Bytes) -> Vec<AssetEvent> {
+        history::read_asset_history(&env, asset_id)
+    }
+
+    pub fn get_rights_record(env: Env, asset_id: Bytes) -> RightsRecord {
+        rights::read_rights_record(&env, asset_id)
+    }
+
+    pub fn get_license(env: Env, license_id: u64) -> License {
+        license::read_license(&env, license_id)
+    }
+
+    pub fn transfer_license(
+        env: Env,
+        license_id: u64,
+        holder: Address,
+        new_holder: Address,
+        price: i128,
+    ) {
+        holder.require_auth();
+        new_holder.require_auth();
+        license::transfer_license(&env, license_id, &holder, &new_holder, &price)
+    }
+
+    pub fn issue_sublicense(
+        env: Env,
+        license_id: u64,
+        holder: Address,
+        sublicensee: Address,
+        price: i128,
+    ) -> u64 {
+        holder.require_auth();
+        sublicensee.require_auth();
+        license::issue_sublicense(&env, license_id, &holder, &sublicensee, &price)
+    }
+
+    pub fn renew_license(env: Env, license_id: u64, payer: Address) {
+        payer.require_auth();
+        license::renew_license(&env, license_id, &payer)
+    }
+
+    pub fn claim_license_revenue(env: Env) -> i128 {
+        payment_contract_info::get_creator(&env).require_auth();
+        license::claim_license_revenue(&env)
+    }
+
+    pub fn is_licensed(env: Env, asset_id: Bytes, licensee: Address, now: u64) -> bool {
+        license::is_licensed(&env, &asset_id, &licensee, &now)
+    }
+
+    pub fn is_licensed_for_use(
+        env: Env,
+        asset_id: Bytes,
+        licensee: Address,
+        territory: Bytes,
+        channels: u32,
+    ) -> bool {
+        license::is_licensed_for_use(&env, &asset_id, &licensee, &territory, channels)
+    }
+
+    pub fn cancel_contract(env: Env) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        payment_contract_info::get_contract_manager_address(&env).require_auth();
+        cancellation::cancel_contract(&env)
+    }
+
+    pub fn propose_settlement(env: Env, proposer: Address, amount: i128) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        settlement::propose_settlement(&env, &proposer, amount)
+    }
+
+    pub fn accept_settlement(env: Env, signer: Address, amount: i128) {
+        if !is_contract_active(&env) {
+            panic_with_error!(env, ContractError::ContractNotActive)
+        }
+        settlement::accept_settlement(&env, &signer, amount)
+    }
+
+    pub fn get_settlement(env: Env) -> Option<Settlement> {
+        settlement::read_settlement(&env)
+    }
+
+    pub fn get_contract_history(env: Env) -> Vec<ContractEvent> {
+        history::read_contract_history(&env)
+    }
+
+    pub fn get_total_paid(env: Env) -> i128 {
+        metadata::get_total_paid(&env)
+    }
+
     pub fn get_contract_state(env: Env) -> ContractState {
         if !is_contract_with_state(&env) {
             panic_with_error!(env, ContractError::ContractNotActive)
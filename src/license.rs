@@ -0,0 +1,288 @@
+use crate::{
+    error::ContractError,
+    payment::transfer,
+    payment_contract_info::{get_creator, get_license_terms, LicenseTerms},
+    storage_types::DataKey,
+};
+use soroban_sdk::{
+    contracttype, map, panic_with_error, unwrap::UnwrapOptimized, Address, Bytes, Env, Map, Vec,
+};
+
+const LICENSES_KEY: DataKey = DataKey::Licenses;
+const NEXT_LICENSE_ID_KEY: DataKey = DataKey::NextLicenseId;
+const LICENSE_REVENUE_KEY: DataKey = DataKey::LicenseRevenue;
+const MAX_ROYALTY_RATE: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum LicenseStatus {
+    Active,
+    /// The license end date already passed according to the ledger time
+    Expired,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// License granted over a paid asset of a licensing contract
+pub struct License {
+    /// Unique identifier of the license within the contract
+    pub id: u64,
+    pub licensee: Address,
+    pub asset_id: Bytes,
+    /// Description of the allowed uses
+    pub scope: Bytes,
+    /// Codes of the territories where the asset can be used, empty for worldwide
+    pub territories: Vec<Bytes>,
+    /// Bitmask of the usage channels where the asset can be used
+    pub channels: u32,
+    pub exclusive: bool,
+    /// The ledger timestamp from which the license is valid
+    pub start_date: u64,
+    /// The date after which the license is no longer valid, 0 for a perpetual license
+    pub end_date: u64,
+    pub status: LicenseStatus,
+    /// Whether the holder can issue sublicenses
+    pub sublicensable: bool,
+    pub origin: LicenseOrigin,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum LicenseOrigin {
+    /// The license was granted when the asset was paid
+    Grant,
+    /// The license is a sublicense issued from the license with the given id
+    Sublicense(u64),
+}
+
+impl License {
+    fn is_valid_at(&self, date: &u64) -> bool {
+        self.start_date <= *date && (self.end_date == 0 || *date <= self.end_date)
+    }
+
+    fn refresh_status(mut self, env: &Env) -> Self {
+        self.status = if self.end_date != 0 && env.ledger().timestamp() > self.end_date {
+            LicenseStatus::Expired
+        } else {
+            LicenseStatus::Active
+        };
+        self
+    }
+}
+
+pub(crate) fn read_licenses(env: &Env) -> Map<u64, License> {
+    match env.storage().get(&LICENSES_KEY) {
+        Some(licenses) => licenses.unwrap(),
+        None => map![env],
+    }
+}
+
+fn write_licenses(env: &Env, licenses: &Map<u64, License>) {
+    env.storage().set(&LICENSES_KEY, licenses)
+}
+
+fn write_license(env: &Env, license: &License) {
+    let mut licenses = read_licenses(env);
+    licenses.set(license.id, license.clone());
+    write_licenses(env, &licenses)
+}
+
+pub(crate) fn read_license(env: &Env, license_id: u64) -> License {
+    match read_licenses(env).get(license_id) {
+        Some(license) => license.unwrap().refresh_status(env),
+        None => panic_with_error!(env, ContractError::LicenseNotFound),
+    }
+}
+
+fn read_terms(env: &Env) -> LicenseTerms {
+    match get_license_terms(env) {
+        Some(terms) => terms,
+        None => panic_with_error!(env, ContractError::MissingLicenseTerms),
+    }
+}
+
+fn store_new_license(env: &Env, mut license: License) -> u64 {
+    let license_id: u64 = match env.storage().get(&NEXT_LICENSE_ID_KEY) {
+        Some(id) => id.unwrap(),
+        None => 0,
+    };
+    license.id = license_id;
+    write_license(env, &license);
+    env.storage().set(&NEXT_LICENSE_ID_KEY, &(license_id + 1));
+    license_id
+}
+
+/// Grants a license that starts at the current ledger time
+pub(crate) fn grant_license(env: &Env, asset_id: Bytes, licensee: &Address) -> u64 {
+    let terms = read_terms(env);
+    let start_date = env.ledger().timestamp();
+    let end_date = if terms.duration == 0 {
+        0
+    } else {
+        start_date + terms.duration
+    };
+    store_new_license(
+        env,
+        License {
+            id: 0,
+            licensee: licensee.clone(),
+            asset_id,
+            scope: terms.scope,
+            territories: terms.territories,
+            channels: terms.channels,
+            exclusive: terms.exclusive,
+            start_date,
+            end_date,
+            status: LicenseStatus::Active,
+            sublicensable: terms.sublicensable,
+            origin: LicenseOrigin::Grant,
+        },
+    )
+}
+
+fn read_active_license(env: &Env, license_id: u64, holder: &Address) -> License {
+    let license = read_license(env, license_id);
+    if license.licensee != *holder {
+        panic_with_error!(env, ContractError::NotLicenseHolder);
+    }
+    if !license.is_valid_at(&env.ledger().timestamp()) {
+        panic_with_error!(env, ContractError::LicenseExpired);
+    }
+    license
+}
+
+/// Pays the license price to the seller, minus the royalty share that goes to the creator
+fn pay_license_price(env: &Env, buyer: &Address, seller: &Address, price: &i128) {
+    if *price <= 0 {
+        return;
+    }
+    let royalty_rate = read_terms(env).royalty_rate.min(MAX_ROYALTY_RATE);
+    let royalty =
+        price.checked_mul(royalty_rate as i128).unwrap_optimized() / MAX_ROYALTY_RATE as i128;
+    if royalty > 0 {
+        transfer(env, buyer, &get_creator(env), &royalty);
+    }
+    transfer(env, buyer, seller, &(price - royalty));
+}
+
+pub(crate) fn transfer_license(
+    env: &Env,
+    license_id: u64,
+    holder: &Address,
+    new_holder: &Address,
+    price: &i128,
+) {
+    let mut license = read_active_license(env, license_id, holder);
+    pay_license_price(env, new_holder, holder, price);
+    license.licensee = new_holder.clone();
+    write_license(env, &license)
+}
+
+pub(crate) fn issue_sublicense(
+    env: &Env,
+    license_id: u64,
+    holder: &Address,
+    sublicensee: &Address,
+    price: &i128,
+) -> u64 {
+    let license = read_active_license(env, license_id, holder);
+    if !license.sublicensable {
+        panic_with_error!(env, ContractError::NotSublicensable);
+    }
+    pay_license_price(env, sublicensee, holder, price);
+    store_new_license(
+        env,
+        License {
+            id: 0,
+            licensee: sublicensee.clone(),
+            asset_id: license.asset_id,
+            scope: license.scope,
+            territories: license.territories,
+            channels: license.channels,
+            exclusive: false,
+            start_date: env.ledger().timestamp(),
+            end_date: license.end_date,
+            status: LicenseStatus::Active,
+            sublicensable: false,
+            origin: LicenseOrigin::Sublicense(license_id),
+        },
+    )
+}
+
+/// Extends the license by another term once the renewal fee is paid into the contract
+pub(crate) fn renew_license(env: &Env, license_id: u64, payer: &Address) {
+    let terms = read_terms(env);
+    let mut license = read_license(env, license_id);
+    if license.end_date == 0 || license.origin != LicenseOrigin::Grant {
+        panic_with_error!(env, ContractError::NotRenewable);
+    }
+    if terms.renewal_fee > 0 {
+        transfer(
+            env,
+            payer,
+            &env.current_contract_address(),
+            &terms.renewal_fee,
+        );
+        let revenue = read_license_revenue(env);
+        env.storage()
+            .set(&LICENSE_REVENUE_KEY, &(revenue + terms.renewal_fee));
+    }
+    license.end_date = license.end_date.max(env.ledger().timestamp()) + terms.duration;
+    write_license(env, &license.refresh_status(env))
+}
+
+pub(crate) fn read_license_revenue(env: &Env) -> i128 {
+    match env.storage().get(&LICENSE_REVENUE_KEY) {
+        Some(revenue) => revenue.unwrap(),
+        None => 0,
+    }
+}
+
+pub(crate) fn claim_license_revenue(env: &Env) -> i128 {
+    let revenue = read_license_revenue(env);
+    if revenue > 0 {
+        transfer(
+            env,
+            &env.current_contract_address(),
+            &get_creator(env),
+            &revenue,
+        );
+        env.storage().set(&LICENSE_REVENUE_KEY, &0_i128);
+    }
+    revenue
+}
+
+pub(crate) fn is_licensed(env: &Env, asset_id: &Bytes, licensee: &Address, now: &u64) -> bool {
+    for license in read_licenses(env).values().iter() {
+        let license = license.unwrap();
+        if license.asset_id == *asset_id
+            && license.licensee == *licensee
+            && license.is_valid_at(now)
+        {
+            return true;
+        }
+    }
+    false
+}
+
+pub(crate) fn is_licensed_for_use(
+    env: &Env,
+    asset_id: &Bytes,
+    licensee: &Address,
+    territory: &Bytes,
+    channels: u32,
+) -> bool {
+    let now = env.ledger().timestamp();
+    for license in read_licenses(env).values().iter() {
+        let license = license.unwrap();
+        if license.asset_id == *asset_id
+            && license.licensee == *licensee
+            && license.is_valid_at(&now)
+            && (license.territories.is_empty() || license.territories.contains(territory))
+            && license.channels & channels == channels
+        {
+            return true;
+        }
+    }
+    false
+}
@@ -1,13 +1,24 @@
 use crate::storage_types::{ContractState, DataKey};
-use soroban_sdk::Env;
+use soroban_sdk::{BytesN, Env};
 
 const CONTRACT_STATE_KEY: DataKey = DataKey::ContractState;
 
-pub fn sign_contract(env: &Env, date: &u64) {
+pub fn sign_contract(env: &Env, date: &u64, terms_hash: &BytesN<32>) {
     let acceptance_date_key = DataKey::DateOfAcceptance;
     env.storage()
         .set(&CONTRACT_STATE_KEY, &ContractState::Active);
     env.storage().set(&acceptance_date_key, date);
+    write_accepted_terms_hash(env, terms_hash);
+}
+
+pub fn write_accepted_terms_hash(env: &Env, terms_hash: &BytesN<32>) {
+    env.storage().set(&DataKey::AcceptedTermsHash, terms_hash)
+}
+
+pub fn get_accepted_terms_hash(env: &Env) -> Option<BytesN<32>> {
+    env.storage()
+        .get(&DataKey::AcceptedTermsHash)
+        .map(|terms_hash| terms_hash.unwrap())
 }
 
 pub fn is_contract_with_state(env: &Env) -> bool {
@@ -25,6 +36,22 @@ pub fn is_contract_active(env: &Env) -> bool {
     }
 }
 
+pub fn set_contract_state(env: &Env, state: &ContractState) {
+    env.storage().set(&CONTRACT_STATE_KEY, state)
+}
+
+pub fn get_total_paid(env: &Env) -> i128 {
+    match env.storage().get(&DataKey::TotalPaid) {
+        Some(total_paid) => total_paid.unwrap(),
+        None => 0,
+    }
+}
+
+pub fn update_total_paid(env: &Env, amount: &i128) {
+    env.storage()
+        .set(&DataKey::TotalPaid, &(get_total_paid(env) + amount))
+}
+
 pub fn get_fee_profit(env: &Env) -> i128 {
     let key = DataKey::FeeProfit;
     match env.storage().get(&key) {
@@ -0,0 +1,151 @@
+use crate::{
+    asset::AssetState,
+    dispute::is_asset_frozen,
+    error::ContractError,
+    payment::{refund_held_funds, release_payout, set_assets_state},
+    storage_types::DataKey,
+};
+use soroban_sdk::{contracttype, map, panic_with_error, vec, Address, Bytes, Env, Map, Vec};
+
+const PAYOUT_POLICY_KEY: DataKey = DataKey::PayoutPolicy;
+const PENDING_PAYOUTS_KEY: DataKey = DataKey::PendingPayouts;
+const NEXT_PAYOUT_ID_KEY: DataKey = DataKey::NextPayoutId;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Threshold policy that requires M-of-N signers to approve large payouts
+pub struct PayoutPolicy {
+    /// Payments above this amount must be approved by the signers before being transferred
+    pub threshold_amount: i128,
+    /// The manager group allowed to approve a pending payout
+    pub signers: Vec<Address>,
+    /// The number of approvals needed to execute a pending payout
+    pub required_approvals: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// A payment held in the contract until enough signers approve it
+pub struct PendingPayout {
+    /// The amount that will be transferred to the creator
+    pub amount: i128,
+    /// The assets that will be marked as paid once the payout is executed
+    pub asset_ids: Vec<Bytes>,
+    /// The date in which the payment was requested
+    pub request_date: u64,
+    /// The signers that already approved the payout
+    pub approvals: Vec<Address>,
+    /// Where the funds held for the payout come from
+    pub funds: PayoutFunds,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The origin of the funds the contract holds for a pending payout, so project money never mixes
+/// with the money of the accounts that prepaid or paid the company payments
+pub enum PayoutFunds {
+    /// Transferred from the given account
+    Account(Address),
+    /// Drawn from the budget of the project in its `ProjectRegistry`
+    Project,
+}
+
+pub(crate) fn write_payout_policy(env: &Env, policy: &PayoutPolicy) {
+    if policy.required_approvals == 0
+        || policy.required_approvals > policy.signers.len()
+        || policy.threshold_amount < 0
+    {
+        panic_with_error!(env, ContractError::InvalidPayoutPolicy);
+    }
+    env.storage().set(&PAYOUT_POLICY_KEY, policy)
+}
+
+pub(crate) fn requires_approval(env: &Env, payment_amount: &i128) -> bool {
+    match env.storage().get(&PAYOUT_POLICY_KEY) {
+        Some(policy) => {
+            let policy: PayoutPolicy = policy.unwrap();
+            *payment_amount > policy.threshold_amount
+        }
+        None => false,
+    }
+}
+
+pub(crate) fn read_pending_payouts(env: &Env) -> Map<u32, PendingPayout> {
+    match env.storage().get(&PENDING_PAYOUTS_KEY) {
+        Some(payouts) => payouts.unwrap(),
+        None => map![env],
+    }
+}
+
+fn write_pending_payouts(env: &Env, payouts: &Map<u32, PendingPayout>) {
+    env.storage().set(&PENDING_PAYOUTS_KEY, payouts)
+}
+
+pub(crate) fn queue_payout(
+    env: &Env,
+    amount: &i128,
+    asset_ids: Vec<Bytes>,
+    date: &u64,
+    funds: PayoutFunds,
+) -> u32 {
+    let payout_id: u32 = match env.storage().get(&NEXT_PAYOUT_ID_KEY) {
+        Some(id) => id.unwrap(),
+        None => 0,
+    };
+    let mut payouts = read_pending_payouts(env);
+    payouts.set(
+        payout_id,
+        PendingPayout {
+            amount: *amount,
+            asset_ids,
+            request_date: *date,
+            approvals: vec![env],
+            funds,
+        },
+    );
+    write_pending_payouts(env, &payouts);
+    env.storage().set(&NEXT_PAYOUT_ID_KEY, &(payout_id + 1));
+    payout_id
+}
+
+/// Drops every pending payout, moving its assets back to approved and refunding the funds it held
+pub(crate) fn cancel_pending_payouts(env: &Env, actor: &Address) {
+    for payout in read_pending_payouts(env).values().iter() {
+        let payout = payout.unwrap();
+        set_assets_state(env, &payout.asset_ids, AssetState::Approved, actor);
+        refund_held_funds(env, &payout.amount, &payout.funds);
+    }
+    env.storage().remove(&PENDING_PAYOUTS_KEY);
+}
+
+pub(crate) fn approve_payout(env: &Env, signer: &Address, payout_id: u32) {
+    let policy: PayoutPolicy = match env.storage().get(&PAYOUT_POLICY_KEY) {
+        Some(policy) => policy.unwrap(),
+        None => panic_with_error!(env, ContractError::NotPayoutSigner),
+    };
+    if !policy.signers.contains(signer) {
+        panic_with_error!(env, ContractError::NotPayoutSigner);
+    }
+    let mut payouts = read_pending_payouts(env);
+    let mut payout = match payouts.get(payout_id) {
+        Some(payout) => payout.unwrap(),
+        None => panic_with_error!(env, ContractError::PayoutNotFound),
+    };
+    if payout.approvals.contains(signer) {
+        panic_with_error!(env, ContractError::PayoutAlreadyApproved);
+    }
+    payout.approvals.push_back(signer.clone());
+    if payout.approvals.len() >= policy.required_approvals {
+        for asset_id in payout.asset_ids.iter() {
+            if is_asset_frozen(env, &asset_id.unwrap()) {
+                panic_with_error!(env, ContractError::AssetsFrozen);
+            }
+        }
+        payouts.remove(payout_id);
+        write_pending_payouts(env, &payouts);
+        release_payout(env, &payout, signer);
+    } else {
+        payouts.set(payout_id, payout);
+        write_pending_payouts(env, &payouts);
+    }
+}
@@ -0,0 +1,86 @@
+//! Module Offer
+//!
+//! Module that lets the creator answer the offer of the company with a counter-offer before
+//! signing. The contract becomes active when the manager accepts it.
+use crate::{
+    delegation::{require_creator_or_delegate, DelegationScope},
+    error::ContractError,
+    metadata::sign_contract,
+    payment_contract_info::{get_contract_info, write_contract_info, PaymentContractInfo},
+    storage_types::DataKey,
+};
+use soroban_sdk::{contracttype, panic_with_error, xdr::ToXdr, Address, BytesN, Env};
+
+const COUNTER_OFFER_KEY: DataKey = DataKey::CounterOffer;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The price and deadline the creator asks for instead of the offered ones
+pub struct CounterOfferTerms {
+    pub asset_payment_amount: i128,
+    pub deadline: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct CounterOffer {
+    pub proposer: Address,
+    pub terms: CounterOfferTerms,
+    /// Ledger timestamp of the counter-offer
+    pub proposal_date: u64,
+    /// Hash of the contract terms with the counter-offer applied
+    pub terms_hash: BytesN<32>,
+}
+
+impl CounterOfferTerms {
+    fn apply(&self, mut contract_info: PaymentContractInfo) -> PaymentContractInfo {
+        contract_info.asset_payment_amount = self.asset_payment_amount;
+        contract_info.deadline = self.deadline;
+        contract_info
+    }
+}
+
+/// Checks the offer against the ledger timestamp, as the date passed by the signer can't be trusted
+pub(crate) fn check_offer_expiry(env: &Env) {
+    let offer_expiry = get_contract_info(env).offer_expiry;
+    if offer_expiry != 0 && env.ledger().timestamp() > offer_expiry {
+        panic_with_error!(env, ContractError::OfferExpired);
+    }
+}
+
+pub(crate) fn read_counter_offer(env: &Env) -> Option<CounterOffer> {
+    env.storage()
+        .get(&COUNTER_OFFER_KEY)
+        .map(|counter_offer| counter_offer.unwrap())
+}
+
+pub(crate) fn submit_counter_offer(env: &Env, proposer: &Address, terms: CounterOfferTerms) {
+    require_creator_or_delegate(env, proposer, DelegationScope::Sign);
+    check_offer_expiry(env);
+    let counter_offer = CounterOffer {
+        proposer: proposer.clone(),
+        terms_hash: env
+            .crypto()
+            .sha256(&terms.apply(get_contract_info(env)).to_xdr(env)),
+        terms,
+        proposal_date: env.ledger().timestamp(),
+    };
+    env.storage().set(&COUNTER_OFFER_KEY, &counter_offer)
+}
+
+pub(crate) fn accept_counter_offer(env: &Env, date: &u64) {
+    let counter_offer = match read_counter_offer(env) {
+        Some(counter_offer) => counter_offer,
+        None => panic_with_error!(env, ContractError::NoCounterOffer),
+    };
+    write_contract_info(env, &counter_offer.terms.apply(get_contract_info(env)));
+    env.storage().remove(&COUNTER_OFFER_KEY);
+    sign_contract(env, date, &counter_offer.terms_hash)
+}
+
+pub(crate) fn reject_counter_offer(env: &Env) {
+    if read_counter_offer(env).is_none() {
+        panic_with_error!(env, ContractError::NoCounterOffer);
+    }
+    env.storage().remove(&COUNTER_OFFER_KEY)
+}
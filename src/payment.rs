@@ -3,73 +3,326 @@ mod token_contract {
 }
 
 use crate::{
-    asset::{read_assets, write_assets, Asset, AssetState},
+    asset::{has_assets, read_assets, write_assets, Asset, AssetState},
+    delegation::calculate_commission,
+    dispute::is_asset_frozen,
     error::ContractError,
-    metadata::update_fee,
+    history::{record_asset_event, state_action},
+    metadata::{update_fee, update_total_paid},
+    multisig::{
+        cancel_pending_payouts, queue_payout, requires_approval, PayoutFunds, PendingPayout,
+    },
     payment_contract_info::{
         get_asset_payment_amount, get_contract_manager_address, get_creator, get_payment_date,
         get_payment_method, get_payment_time, PaymentMethod,
     },
+    project::{fund_from_project, return_to_project},
+    rights::record_rights_transfer,
+    storage_types::DataKey,
 };
 use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, vec, Address, Bytes, Env, Map, Vec};
 
 pub(crate) fn execute_payment(env: &Env, date: &u64, prepayment_source: &Option<Address>) {
     let payment_date = get_payment_date(env);
     let payment_time = get_payment_time(env);
-    let payment_method = get_payment_method(env);
     let contract_manager_address = get_contract_manager_address(env);
-    let creator_address = get_creator(env);
     let asset_payment_amount = get_asset_payment_amount(env);
     let (payment_amount, assets_to_pay) = calculate_payment_amount(env, &asset_payment_amount);
+    let client = get_token_client(env);
+
+    let (source, amount) = if payment_date > *date && payment_time != 0 {
+        let prepayment_source = prepayment_source.clone().unwrap();
+        (
+            prepayment_source,
+            calculate_prepayment_amount(env, &payment_amount),
+        )
+    } else {
+        (contract_manager_address.clone(), payment_amount)
+    };
+    source.require_auth();
+    let funding_source = if source == contract_manager_address {
+        fund_from_project(env, source.clone(), &amount)
+    } else {
+        source.clone()
+    };
+
+    if requires_approval(env, &payment_amount) {
+        hold_payout(
+            env,
+            &client,
+            &source,
+            &funding_source,
+            &amount,
+            assets_to_pay,
+            date,
+        );
+    } else {
+        pay_creator(env, &client, &funding_source, &amount);
+        set_assets_as_paid(env, assets_to_pay, &source);
+    }
+}
 
-    match payment_method {
-        PaymentMethod::Native(contract_id) => {
-            let client = token_contract::Client::new(env, &contract_id);
-            if payment_date > *date && payment_time != 0 {
-                prepayment_source.clone().unwrap().require_auth();
-                execute_prepayment(
-                    env,
-                    &payment_amount,
-                    &prepayment_source.clone().unwrap(),
-                    &creator_address,
-                    &client,
-                )
-            } else {
-                contract_manager_address.require_auth();
-                client.xfer(&contract_manager_address, &creator_address, &payment_amount);
+pub(crate) fn release_payout(env: &Env, payout: &PendingPayout, signer: &Address) {
+    let client = get_token_client(env);
+    pay_creator(
+        env,
+        &client,
+        &env.current_contract_address(),
+        &payout.amount,
+    );
+    set_assets_as_paid(env, payout.asset_ids.clone(), signer);
+}
+
+/// Pays the amount of a partial payment ruling for the disputed assets that are still unpaid. The
+/// rejected ones keep their state since the company doesn't take the work
+pub(crate) fn execute_partial_payment(env: &Env, amount: &i128, asset_ids: &Vec<Bytes>) {
+    let submitted_assets: Map<Bytes, Asset> = read_assets(env);
+    let mut assets_to_pay: Vec<Bytes> = vec![env];
+    let mut has_unpaid_assets = false;
+    for asset_id in asset_ids.iter() {
+        let id = asset_id.unwrap();
+        match submitted_assets.get_unchecked(id.clone()).unwrap().state {
+            AssetState::InReview | AssetState::Approved => {
+                assets_to_pay.push_back(id);
+                has_unpaid_assets = true
             }
+            AssetState::Rejected => has_unpaid_assets = true,
+            AssetState::PaymentPending | AssetState::Paid => {}
         }
     }
-    set_assets_as_paid(env, assets_to_pay);
+    if !has_unpaid_assets {
+        panic_with_error!(env, ContractError::NoApprovedAssets);
+    }
+    pay_from_company(env, amount, &assets_to_pay)
+}
+
+/// Pays every approved asset with the company funds, regardless of the payment date
+pub(crate) fn pay_approved_assets(env: &Env) {
+    let asset_ids = collect_payable_assets(env);
+    if asset_ids.is_empty() {
+        return;
+    }
+    let amount = get_asset_payment_amount(env)
+        .checked_mul(asset_ids.len() as i128)
+        .unwrap_optimized();
+    pay_from_company(env, &amount, &asset_ids)
+}
+
+/// Pays the amount to the creator from the manager account or the project budget, marking the given
+/// assets as paid. The amount is held in the contract instead when it needs the approval of the
+/// payout signers
+pub(crate) fn pay_from_company(env: &Env, amount: &i128, asset_ids: &Vec<Bytes>) {
+    let contract_manager_address = get_contract_manager_address(env);
+    contract_manager_address.require_auth();
+    let client = get_token_client(env);
+    let source = fund_from_project(env, contract_manager_address.clone(), amount);
+    if requires_approval(env, amount) {
+        hold_payout(
+            env,
+            &client,
+            &contract_manager_address,
+            &source,
+            amount,
+            asset_ids.clone(),
+            &env.ledger().timestamp(),
+        );
+    } else {
+        pay_creator(env, &client, &source, amount);
+        set_assets_as_paid(env, asset_ids.clone(), &contract_manager_address);
+    }
 }
 
-fn execute_prepayment(
+/// Holds the amount in the contract until the payout signers approve it, keeping track of whether it
+/// came from the payer account or the project budget
+fn hold_payout(
     env: &Env,
-    payment_amount: &i128,
-    prepayment_source: &Address,
-    creator_address: &Address,
     client: &token_contract::Client,
+    payer: &Address,
+    source: &Address,
+    amount: &i128,
+    asset_ids: Vec<Bytes>,
+    date: &u64,
 ) {
+    let funds = hold_funds(env, client, source, amount);
+    set_assets_state(env, &asset_ids, AssetState::PaymentPending, payer);
+    queue_payout(env, amount, asset_ids, date, funds);
+}
+
+/// Moves the amount from the source into the contract, returning where the held funds come from.
+/// The funds drawn from the project budget are already in the contract
+fn hold_funds(
+    env: &Env,
+    client: &token_contract::Client,
+    source: &Address,
+    amount: &i128,
+) -> PayoutFunds {
+    if *source == env.current_contract_address() {
+        PayoutFunds::Project
+    } else {
+        client.xfer(source, &env.current_contract_address(), amount);
+        PayoutFunds::Account(source.clone())
+    }
+}
+
+/// Holds in the contract an amount the company will pay, drawing it from the project budget when
+/// the contract has one
+pub(crate) fn hold_company_funds(env: &Env, amount: &i128) -> PayoutFunds {
+    let contract_manager_address = get_contract_manager_address(env);
+    contract_manager_address.require_auth();
+    let source = fund_from_project(env, contract_manager_address, amount);
+    hold_funds(env, &get_token_client(env), &source, amount)
+}
+
+/// Pays the creator an amount already held in the contract, marking the given assets as paid. It's
+/// queued instead when it needs the approval of the payout signers
+pub(crate) fn pay_held_funds(
+    env: &Env,
+    amount: &i128,
+    funds: PayoutFunds,
+    asset_ids: Vec<Bytes>,
+    actor: &Address,
+) {
+    if requires_approval(env, amount) {
+        set_assets_state(env, &asset_ids, AssetState::PaymentPending, actor);
+        queue_payout(env, amount, asset_ids, &env.ledger().timestamp(), funds);
+    } else {
+        pay_creator(
+            env,
+            &get_token_client(env),
+            &env.current_contract_address(),
+            amount,
+        );
+        set_assets_as_paid(env, asset_ids, actor);
+    }
+}
+
+/// Pays the given approved assets with the funds the manager escrowed in the contract, so nobody
+/// from the company has to authorize it. Nothing is paid when the escrow can't cover them
+pub(crate) fn pay_from_escrow(env: &Env, asset_ids: &Vec<Bytes>) {
+    let mut assets_to_pay: Vec<Bytes> = vec![env];
+    for asset_id in asset_ids.iter() {
+        let asset_id = asset_id.unwrap();
+        if !is_asset_frozen(env, &asset_id) {
+            assets_to_pay.push_back(asset_id);
+        }
+    }
+    let amount = get_asset_payment_amount(env)
+        .checked_mul(assets_to_pay.len() as i128)
+        .unwrap_optimized();
+    let escrow = read_escrow(env);
+    if assets_to_pay.is_empty() || escrow < amount {
+        return;
+    }
+    write_escrow(env, &(escrow - amount));
+    pay_held_funds(
+        env,
+        &amount,
+        PayoutFunds::Account(get_contract_manager_address(env)),
+        assets_to_pay,
+        &env.current_contract_address(),
+    )
+}
+
+pub(crate) fn fund_escrow(env: &Env, amount: &i128) {
+    let contract_manager_address = get_contract_manager_address(env);
+    contract_manager_address.require_auth();
+    transfer(
+        env,
+        &contract_manager_address,
+        &env.current_contract_address(),
+        amount,
+    );
+    write_escrow(env, &(read_escrow(env) + amount))
+}
+
+pub(crate) fn read_escrow(env: &Env) -> i128 {
+    match env.storage().get(&DataKey::Escrow) {
+        Some(escrow) => escrow.unwrap(),
+        None => 0,
+    }
+}
+
+fn write_escrow(env: &Env, escrow: &i128) {
+    env.storage().set(&DataKey::Escrow, escrow)
+}
+
+/// Returns the escrow to the manager and the funds held by the pending payouts to where they came
+/// from, moving their assets back to approved
+pub(crate) fn refund_escrow(env: &Env, actor: &Address) {
+    cancel_pending_payouts(env, actor);
+    let escrow = read_escrow(env);
+    if escrow > 0 {
+        write_escrow(env, &0);
+        transfer(
+            env,
+            &env.current_contract_address(),
+            &get_contract_manager_address(env),
+            &escrow,
+        );
+    }
+}
+
+/// Returns an amount held in the contract to the account or the project budget it came from
+pub(crate) fn refund_held_funds(env: &Env, amount: &i128, funds: &PayoutFunds) {
+    match funds {
+        PayoutFunds::Account(address) => {
+            transfer(env, &env.current_contract_address(), address, amount)
+        }
+        PayoutFunds::Project => return_to_project(env, amount),
+    }
+}
+
+/// Lets the spender take the amount from the contract funds
+pub(crate) fn allow_spender(env: &Env, spender: &Address, amount: &i128) {
+    get_token_client(env).incr_allow(&env.current_contract_address(), spender, amount)
+}
+
+fn pay_creator(env: &Env, client: &token_contract::Client, source: &Address, amount: &i128) {
+    let creator_address = get_creator(env);
+    let mut creator_amount = *amount;
+    if let Some((delegate, commission)) = calculate_commission(env, amount) {
+        client.xfer(source, &delegate, &commission);
+        creator_amount -= commission;
+    }
+    client.xfer(source, &creator_address, &creator_amount);
+    update_total_paid(env, amount);
+}
+
+pub(crate) fn transfer(env: &Env, from: &Address, to: &Address, amount: &i128) {
+    get_token_client(env).xfer(from, to, amount)
+}
+
+fn get_token_client(env: &Env) -> token_contract::Client {
+    match get_payment_method(env) {
+        PaymentMethod::Native(contract_id) => token_contract::Client::new(env, &contract_id),
+    }
+}
+
+fn calculate_prepayment_amount(env: &Env, payment_amount: &i128) -> i128 {
     let payment_amount = *payment_amount as f64;
     let fee = payment_amount * 0.1_f64;
     let prepayment_amount = payment_amount - fee;
     update_fee(env, &(fee as i128));
-    client.xfer(
-        prepayment_source,
-        creator_address,
-        &(prepayment_amount as i128),
-    )
+    prepayment_amount as i128
 }
 
-fn calculate_payment_amount(env: &Env, asset_payment_amount: &i128) -> (i128, Vec<Bytes>) {
-    let submitted_assets: Map<Bytes, Asset> = read_assets(env);
+fn collect_payable_assets(env: &Env) -> Vec<Bytes> {
     let mut asset_ids: Vec<Bytes> = vec![env];
+    if !has_assets(env) {
+        return asset_ids;
+    }
+    let submitted_assets: Map<Bytes, Asset> = read_assets(env);
     for asset in submitted_assets.iter() {
         let (id, data) = asset.unwrap();
-        if data.state == AssetState::Approved {
+        if data.state == AssetState::Approved && !is_asset_frozen(env, &id) {
             asset_ids.push_front(id)
         }
     }
+    asset_ids
+}
+
+fn calculate_payment_amount(env: &Env, asset_payment_amount: &i128) -> (i128, Vec<Bytes>) {
+    let asset_ids = collect_payable_assets(env);
     if asset_ids.is_empty() {
         panic_with_error!(env, ContractError::NoApprovedAssets);
     }
@@ -79,12 +332,28 @@ fn calculate_payment_amount(env: &Env, asset_payment_amount: &i128) -> (i128, Ve
     (total_payment_amount, asset_ids)
 }
 
-fn set_assets_as_paid(env: &Env, assets_to_pay: Vec<Bytes>) {
+fn set_assets_as_paid(env: &Env, assets_to_pay: Vec<Bytes>, payer: &Address) {
+    set_assets_state(env, &assets_to_pay, AssetState::Paid, payer);
+    record_rights_transfer(env, &assets_to_pay, payer)
+}
+
+pub(crate) fn set_assets_state(
+    env: &Env,
+    asset_ids: &Vec<Bytes>,
+    state: AssetState,
+    actor: &Address,
+) {
+    if asset_ids.is_empty() {
+        return;
+    }
     let mut submitted_assets: Map<Bytes, Asset> = read_assets(env);
-    assets_to_pay.iter().for_each(|asset_id| {
+    asset_ids.iter().for_each(|asset_id| {
         let id = asset_id.unwrap();
         let mut asset = submitted_assets.get_unchecked(id.clone()).unwrap();
-        asset.state = AssetState::Paid;
+        asset.state = state.clone();
+        if let Some(action) = state_action(&state) {
+            record_asset_event(env, &id, action, actor);
+        }
         submitted_assets.set(id, asset);
     });
     write_assets(env, &submitted_assets);
@@ -1,5 +1,6 @@
 use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env};
+pub use soropay_types::{ArbitrationPanel, ContractType, KillFee, LicenseTerms, OfferedLicense};
 
 const CONTRACT_INFO_KEY: DataKey = DataKey::PaymentContractInfo;
 const AUTH_PARTNER_KEY: DataKey = DataKey::AuthorizedPartner;
@@ -31,6 +32,25 @@ pub struct PaymentContractInfo {
     pub rights_royalties: Bytes,
     pub payment_time: u64,
     pub contract_type: ContractType,
+    /// The panel in charge of resolving disputes between the parties
+    pub arbitration_panel: ArbitrationPanel,
+    /// Time the parties have to submit evidence after a dispute is opened
+    pub dispute_response_window: u64,
+    /// The `AssetRegistry` contract where the submitted works are registered
+    pub asset_registry: LinkedContract,
+    /// The terms of the licenses granted over the paid assets of a licensing contract
+    pub license_terms: OfferedLicense,
+    /// The `ProjectRegistry` contract whose budget funds the company payments
+    pub project_registry: LinkedContract,
+    /// The ledger timestamp after which the creator can no longer sign the offer, 0 if it doesn't
+    /// expire
+    pub offer_expiry: u64,
+    /// The total amount the company plans to pay to the creator
+    pub budget: i128,
+    /// What the company pays to the creator if it cancels the signed contract
+    pub kill_fee: KillFee,
+    /// Time the manager has to review an asset before anyone can approve it, 0 to disable it
+    pub review_window: u64,
 }
 
 #[contracttype]
@@ -40,14 +60,6 @@ pub enum PaymentMethod {
     Native(BytesN<32>),
 }
 
-#[contracttype]
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub enum ContractType {
-    FixedPrice,
-    Milestones,
-    Licensing,
-}
-
 #[derive(Clone, PartialEq, Eq, Debug)]
 #[contracttype]
 pub struct ContractManager {
@@ -57,6 +69,14 @@ pub struct ContractManager {
     pub physical_address: Bytes,
 }
 
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// Reference to another contract the payment contract works with
+pub enum LinkedContract {
+    None,
+    Id(BytesN<32>),
+}
+
 pub(crate) fn has_contact_info(env: &Env) -> bool {
     env.storage().has(&CONTRACT_INFO_KEY)
 }
@@ -105,4 +125,56 @@ pub(crate) fn get_asset_payment_amount(env: &Env) -> i128 {
     let contract_info: PaymentContractInfo =
         env.storage().get_unchecked(&CONTRACT_INFO_KEY).unwrap();
     contract_info.asset_payment_amount
+}
+
+pub(crate) fn get_arbitration_panel(env: &Env) -> ArbitrationPanel {
+    let contract_info: PaymentContractInfo =
+        env.storage().get_unchecked(&CONTRACT_INFO_KEY).unwrap();
+    contract_info.arbitration_panel
+}
+
+pub(crate) fn get_dispute_response_window(env: &Env) -> u64 {
+    let contract_info: PaymentContractInfo =
+        env.storage().get_unchecked(&CONTRACT_INFO_KEY).unwrap();
+    contract_info.dispute_response_window
+}
+
+pub(crate) fn get_asset_registry(env: &Env) -> Option<BytesN<32>> {
+    let contract_info: PaymentContractInfo =
+        env.storage().get_unchecked(&CONTRACT_INFO_KEY).unwrap();
+    match contract_info.asset_registry {
+        LinkedContract::None => None,
+        LinkedContract::Id(registry_id) => Some(registry_id),
+    }
+}
+
+pub(crate) fn get_project_id(env: &Env) -> Bytes {
+    let contract_info: PaymentContractInfo =
+        env.storage().get_unchecked(&CONTRACT_INFO_KEY).unwrap();
+    contract_info.project_id
+}
+
+pub(crate) fn get_project_registry(env: &Env) -> Option<BytesN<32>> {
+    let contract_info: PaymentContractInfo =
+        env.storage().get_unchecked(&CONTRACT_INFO_KEY).unwrap();
+    match contract_info.project_registry {
+        LinkedContract::None => None,
+        LinkedContract::Id(registry_id) => Some(registry_id),
+    }
+}
+
+pub(crate) fn get_terms_hash(env: &Env) -> BytesN<32> {
+    let contract_info = get_contract_info(env);
+    env.crypto().sha256(&contract_info.to_xdr(env))
+}
+
+pub(crate) fn get_license_terms(env: &Env) -> Option<LicenseTerms> {
+    match get_contract_info(env).license_terms {
+        OfferedLicense::None => None,
+        OfferedLicense::Terms(terms) => Some(terms),
+    }
+}
+
+pub(crate) fn get_review_window(env: &Env) -> u64 {
+    get_contract_info(env).review_window
 }
\ No newline at end of file
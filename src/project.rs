@@ -0,0 +1,49 @@
+//! Module Project
+//!
+//! Module that funds the company payments of the contract with the budget committed to it in its
+//! `ProjectRegistry` contract.
+use crate::{
+    payment::allow_spender,
+    payment_contract_info::{get_project_id, get_project_registry},
+};
+use soroban_sdk::{contractclient, unwrap::UnwrapOptimized, Address, Bytes, Env};
+
+/// Interface of the `ProjectRegistry` contract used by the payment contract, only the generated
+/// client is called
+#[allow(dead_code)]
+#[contractclient(name = "ProjectRegistryClient")]
+pub trait ProjectRegistry {
+    fn draw_budget(env: Env, project_id: Bytes, contract: Address, amount: i128);
+
+    fn return_budget(env: Env, project_id: Bytes, contract: Address, amount: i128);
+}
+
+/// Draws the amount from the project budget into the contract when it belongs to a project
+/// registry, returning the address that must fund the payment
+pub(crate) fn fund_from_project(env: &Env, payer: Address, amount: &i128) -> Address {
+    match get_project_registry(env) {
+        Some(registry_id) => {
+            if *amount > 0 {
+                ProjectRegistryClient::new(env, &registry_id).draw_budget(
+                    &get_project_id(env),
+                    &env.current_contract_address(),
+                    amount,
+                );
+            }
+            env.current_contract_address()
+        }
+        None => payer,
+    }
+}
+
+/// Gives back to the project budget an amount the contract drew but won't pay. The registry takes
+/// it with an allowance, since the contract only authorizes the calls it makes directly
+pub(crate) fn return_to_project(env: &Env, amount: &i128) {
+    let registry_id = get_project_registry(env).unwrap_optimized();
+    allow_spender(env, &Address::from_contract_id(env, &registry_id), amount);
+    ProjectRegistryClient::new(env, &registry_id).return_budget(
+        &get_project_id(env),
+        &env.current_contract_address(),
+        amount,
+    )
+}
@@ -0,0 +1,105 @@
+//! Module Quorum
+//!
+//! Module that requires several named reviewers to vote on each asset before the manager can
+//! approve it. Once enough reviewers reject an asset that the quorum can't be met, the asset is
+//! rejected.
+use crate::{
+    asset::{read_assets, update_assets_state, AssetState},
+    error::ContractError,
+    storage_types::DataKey,
+};
+use soroban_sdk::{contracttype, map, panic_with_error, vec, Address, Bytes, Env, Map, Vec};
+
+const REVIEW_POLICY_KEY: DataKey = DataKey::ReviewPolicy;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Policy that requires K-of-N reviewers to approve an asset
+pub struct ReviewPolicy {
+    pub reviewers: Vec<Address>,
+    /// The number of approve votes an asset needs before it can be approved
+    pub required_approvals: u32,
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum ReviewVote {
+    Approve,
+    /// The asset is rejected once the remaining reviewers can't reach the required approvals
+    Reject,
+}
+
+pub(crate) fn write_review_policy(env: &Env, policy: &ReviewPolicy) {
+    if policy.required_approvals == 0 || policy.required_approvals > policy.reviewers.len() {
+        panic_with_error!(env, ContractError::InvalidReviewPolicy);
+    }
+    env.storage().set(&REVIEW_POLICY_KEY, policy)
+}
+
+pub(crate) fn read_review_policy(env: &Env) -> Option<ReviewPolicy> {
+    env.storage()
+        .get(&REVIEW_POLICY_KEY)
+        .map(|policy| policy.unwrap())
+}
+
+pub(crate) fn read_review_votes(env: &Env, asset_id: Bytes) -> Map<Address, ReviewVote> {
+    match env.storage().get(&DataKey::ReviewVotes(asset_id)) {
+        Some(votes) => votes.unwrap(),
+        None => map![env],
+    }
+}
+
+/// Drops the votes of an asset, used when the creator submits a new version of it
+pub(crate) fn clear_review_votes(env: &Env, asset_id: &Bytes) {
+    env.storage()
+        .remove(&DataKey::ReviewVotes(asset_id.clone()))
+}
+
+pub(crate) fn vote_on_asset(env: &Env, reviewer: &Address, asset_id: Bytes, vote: ReviewVote) {
+    let policy = match read_review_policy(env) {
+        Some(policy) if policy.reviewers.contains(reviewer) => policy,
+        _ => panic_with_error!(env, ContractError::NotReviewer),
+    };
+    match read_assets(env)
+        .get(asset_id.clone())
+        .map(|asset| asset.unwrap().state)
+    {
+        Some(AssetState::InReview) => {}
+        Some(_) => panic_with_error!(env, ContractError::AssetImmutable),
+        None => panic_with_error!(env, ContractError::AssetsNotFound),
+    }
+    let mut votes = read_review_votes(env, asset_id.clone());
+    if votes.contains_key(reviewer.clone()) {
+        panic_with_error!(env, ContractError::AlreadyVoted);
+    }
+    votes.set(reviewer.clone(), vote);
+    env.storage()
+        .set(&DataKey::ReviewVotes(asset_id.clone()), &votes);
+    let rejections = count_votes(&policy, &votes, ReviewVote::Reject);
+    if rejections > policy.reviewers.len() - policy.required_approvals {
+        update_assets_state(env, &vec![env, asset_id], AssetState::Rejected, reviewer);
+    }
+}
+
+/// Whether enough reviewers approved the asset, always true when there is no review policy
+pub(crate) fn has_review_quorum(env: &Env, asset_id: &Bytes) -> bool {
+    match read_review_policy(env) {
+        Some(policy) => {
+            let votes = read_review_votes(env, asset_id.clone());
+            count_votes(&policy, &votes, ReviewVote::Approve) >= policy.required_approvals
+        }
+        None => true,
+    }
+}
+
+/// Counts the votes of the current reviewers that match the given vote
+fn count_votes(policy: &ReviewPolicy, votes: &Map<Address, ReviewVote>, vote: ReviewVote) -> u32 {
+    let mut count = 0;
+    for reviewer_vote in votes.iter() {
+        let (reviewer, reviewer_vote) = reviewer_vote.unwrap();
+        if reviewer_vote == vote && policy.reviewers.contains(&reviewer) {
+            count += 1;
+        }
+    }
+    count
+}
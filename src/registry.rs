@@ -0,0 +1,58 @@
+//! Module Registry
+//!
+//! Module that keeps the `AssetRegistry` contract of the payment contract up to date with the
+//! submitted works and checks their authorship and infringement flags.
+use crate::payment_contract_info::get_asset_registry;
+use soroban_sdk::{contractclient, Address, BytesN, Env};
+
+/// Interface of the `AssetRegistry` contract used by the payment contract, only the generated
+/// client is called
+#[allow(dead_code)]
+#[contractclient(name = "AssetRegistryClient")]
+pub trait AssetRegistry {
+    fn register_work(
+        env: Env,
+        source_contract: Address,
+        content_hash: BytesN<32>,
+        creator: Address,
+        parent: Option<BytesN<32>>,
+    );
+
+    fn is_registered(env: Env, content_hash: BytesN<32>) -> bool;
+
+    fn is_flagged(env: Env, content_hash: BytesN<32>) -> bool;
+}
+
+pub(crate) fn register_work(
+    env: &Env,
+    content_hash: &BytesN<32>,
+    creator: &Address,
+    parent: &Option<BytesN<32>>,
+) {
+    if let Some(registry_id) = get_asset_registry(env) {
+        AssetRegistryClient::new(env, &registry_id).register_work(
+            &env.current_contract_address(),
+            content_hash,
+            creator,
+            parent,
+        );
+    }
+}
+
+/// Whether the work with the given content hash is in the asset registry of the contract
+pub(crate) fn is_registered_work(env: &Env, content_hash: &BytesN<32>) -> bool {
+    match get_asset_registry(env) {
+        Some(registry_id) => {
+            AssetRegistryClient::new(env, &registry_id).is_registered(content_hash)
+        }
+        None => false,
+    }
+}
+
+/// Whether an upheld infringement claim flagged the work in the asset registry of the contract
+pub(crate) fn is_flagged_work(env: &Env, content_hash: &BytesN<32>) -> bool {
+    match get_asset_registry(env) {
+        Some(registry_id) => AssetRegistryClient::new(env, &registry_id).is_flagged(content_hash),
+        None => false,
+    }
+}
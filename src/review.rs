@@ -0,0 +1,49 @@
+//! Module Review
+//!
+//! Module that approves the assets the manager didn't review within the review window, so the
+//! creator doesn't wait indefinitely for an answer. The window only stands in for the manager, so
+//! the assets still need the approvals of the reviewer quorum.
+use crate::{
+    asset::{check_if_has_assets, read_assets, update_assets_state, AssetState},
+    error::ContractError,
+    payment::pay_from_escrow,
+    payment_contract_info::get_review_window,
+    quorum::has_review_quorum,
+};
+use soroban_sdk::{panic_with_error, vec, Bytes, Env, Vec};
+
+/// Approves the given assets whose review window is over and pays them from the escrow. The assets
+/// without the reviewer quorum are skipped, and the ones the escrow can't cover stay approved until
+/// the company pays them with `execute_payment`
+pub(crate) fn finalize_review(env: &Env, asset_ids: Vec<Bytes>) {
+    check_if_has_assets(env);
+    let review_window = get_review_window(env);
+    if review_window == 0 {
+        panic_with_error!(env, ContractError::WindowOpen);
+    }
+    let assets = read_assets(env);
+    let mut expired_ids: Vec<Bytes> = vec![env];
+    for asset_id in asset_ids.iter() {
+        let asset_id = asset_id.unwrap();
+        let asset = match assets.get(asset_id.clone()) {
+            Some(asset) => asset.unwrap(),
+            None => panic_with_error!(env, ContractError::AssetsNotFound),
+        };
+        if asset.state != AssetState::InReview {
+            continue;
+        }
+        if env.ledger().timestamp() <= asset.submission_date.saturating_add(review_window) {
+            panic_with_error!(env, ContractError::WindowOpen);
+        }
+        if has_review_quorum(env, &asset_id) {
+            expired_ids.push_back(asset_id);
+        }
+    }
+    update_assets_state(
+        env,
+        &expired_ids,
+        AssetState::Approved,
+        &env.current_contract_address(),
+    );
+    pay_from_escrow(env, &expired_ids)
+}
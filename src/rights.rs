@@ -0,0 +1,78 @@
+use crate::{
+    error::ContractError,
+    history::{record_asset_event, AssetAction},
+    license::grant_license,
+    payment_contract_info::{get_contract_info, get_creator, get_terms_hash, ContractType},
+    storage_types::DataKey,
+};
+use soroban_sdk::{contracttype, map, panic_with_error, Address, Bytes, BytesN, Env, Map, Vec};
+
+const RIGHTS_RECORDS_KEY: DataKey = DataKey::RightsRecords;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum RightsTransfer {
+    /// The rights moved to the company as a work for hire
+    Assignment,
+    /// The creator keeps the rights and the company is granted the license with the given id
+    License(u64),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Ownership record of a paid asset
+pub struct RightsRecord {
+    /// The current holder of the asset rights
+    pub rights_holder: Address,
+    /// The date in which the rights were assigned or licensed
+    pub assignment_date: u64,
+    /// Hash of the contract terms under which the rights were transferred
+    pub terms_hash: BytesN<32>,
+    pub transfer: RightsTransfer,
+}
+
+pub(crate) fn read_rights_records(env: &Env) -> Map<Bytes, RightsRecord> {
+    match env.storage().get(&RIGHTS_RECORDS_KEY) {
+        Some(records) => records.unwrap(),
+        None => map![env],
+    }
+}
+
+pub(crate) fn read_rights_record(env: &Env, asset_id: Bytes) -> RightsRecord {
+    match read_rights_records(env).get(asset_id) {
+        Some(record) => record.unwrap(),
+        None => panic_with_error!(env, ContractError::RightsRecordNotFound),
+    }
+}
+
+pub(crate) fn record_rights_transfer(env: &Env, asset_ids: &Vec<Bytes>, payer: &Address) {
+    let contract_info = get_contract_info(env);
+    let company = contract_info.contract_manager.address;
+    let terms_hash = get_terms_hash(env);
+    let creator = get_creator(env);
+    let mut records = read_rights_records(env);
+    for asset_id in asset_ids.iter() {
+        let asset_id = asset_id.unwrap();
+        let record = match contract_info.contract_type {
+            ContractType::Licensing => RightsRecord {
+                rights_holder: creator.clone(),
+                assignment_date: env.ledger().timestamp(),
+                terms_hash: terms_hash.clone(),
+                transfer: RightsTransfer::License(grant_license(env, asset_id.clone(), &company)),
+            },
+            ContractType::FixedPrice | ContractType::Milestones => RightsRecord {
+                rights_holder: company.clone(),
+                assignment_date: env.ledger().timestamp(),
+                terms_hash: terms_hash.clone(),
+                transfer: RightsTransfer::Assignment,
+            },
+        };
+        let action = match record.transfer {
+            RightsTransfer::License(license_id) => AssetAction::LicenseGranted(license_id),
+            RightsTransfer::Assignment => AssetAction::RightsAssigned(record.rights_holder.clone()),
+        };
+        record_asset_event(env, &asset_id, action, payer);
+        records.set(asset_id, record);
+    }
+    env.storage().set(&RIGHTS_RECORDS_KEY, &records)
+}
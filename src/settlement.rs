@@ -0,0 +1,94 @@
+//! Module Settlement
+//!
+//! Module that lets both parties terminate a signed contract by agreeing on a settlement amount.
+//! The company funds the settlement when it signs it, the escrow and the pending payouts are refunded
+//! and the contract finishes.
+use crate::{
+    amendment::require_party,
+    error::ContractError,
+    history::{record_contract_event, ContractAction},
+    metadata::set_contract_state,
+    multisig::PayoutFunds,
+    payment::{hold_company_funds, pay_held_funds, refund_escrow, refund_held_funds},
+    payment_contract_info::get_contract_manager_address,
+    storage_types::{ContractState, DataKey},
+};
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Env};
+
+const SETTLEMENT_KEY: DataKey = DataKey::Settlement;
+const SETTLEMENT_FUNDS_KEY: DataKey = DataKey::SettlementFunds;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+pub struct Settlement {
+    pub proposer: Address,
+    /// The amount the company pays to the creator to terminate the contract
+    pub amount: i128,
+    pub proposal_date: u64,
+}
+
+pub(crate) fn read_settlement(env: &Env) -> Option<Settlement> {
+    env.storage()
+        .get(&SETTLEMENT_KEY)
+        .map(|settlement| settlement.unwrap())
+}
+
+fn read_settlement_funds(env: &Env) -> Option<PayoutFunds> {
+    env.storage()
+        .get(&SETTLEMENT_FUNDS_KEY)
+        .map(|funds| funds.unwrap())
+}
+
+/// Drops the pending settlement, refunding the funds the company held for it
+pub(crate) fn drop_settlement(env: &Env) {
+    if let (Some(settlement), Some(funds)) = (read_settlement(env), read_settlement_funds(env)) {
+        refund_held_funds(env, &settlement.amount, &funds);
+    }
+    env.storage().remove(&SETTLEMENT_KEY);
+    env.storage().remove(&SETTLEMENT_FUNDS_KEY);
+}
+
+pub(crate) fn propose_settlement(env: &Env, proposer: &Address, amount: i128) {
+    let proposer_is_company = require_party(env, proposer);
+    if amount < 0 {
+        panic_with_error!(env, ContractError::SettlementMismatch);
+    }
+    drop_settlement(env);
+    if proposer_is_company && amount > 0 {
+        env.storage()
+            .set(&SETTLEMENT_FUNDS_KEY, &hold_company_funds(env, &amount));
+    }
+    let settlement = Settlement {
+        proposer: proposer.clone(),
+        amount,
+        proposal_date: env.ledger().timestamp(),
+    };
+    env.storage().set(&SETTLEMENT_KEY, &settlement);
+    record_contract_event(env, ContractAction::SettlementProposed(amount), proposer)
+}
+
+pub(crate) fn accept_settlement(env: &Env, signer: &Address, amount: i128) {
+    let settlement = match read_settlement(env) {
+        Some(settlement) => settlement,
+        None => panic_with_error!(env, ContractError::NoPendingSettlement),
+    };
+    let signer_is_company = require_party(env, signer);
+    if signer_is_company == (settlement.proposer == get_contract_manager_address(env)) {
+        panic_with_error!(env, ContractError::NotCounterparty);
+    }
+    if amount != settlement.amount {
+        panic_with_error!(env, ContractError::SettlementMismatch);
+    }
+    refund_escrow(env, signer);
+    if amount > 0 {
+        let funds = match read_settlement_funds(env) {
+            Some(funds) => funds,
+            None => hold_company_funds(env, &amount),
+        };
+        pay_held_funds(env, &amount, funds, vec![env], signer);
+    }
+    env.storage().remove(&SETTLEMENT_KEY);
+    env.storage().remove(&SETTLEMENT_FUNDS_KEY);
+    record_contract_event(env, ContractAction::Terminated(amount), signer);
+    set_contract_state(env, &ContractState::Finished)
+}
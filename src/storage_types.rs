@@ -1,7 +1,7 @@
 //! Module StorageTypes
 //!
 //! Module that defines the set of keys that can be used to access and store data within the contract.
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Bytes};
 
 #[derive(Clone)]
 #[contracttype]
@@ -18,6 +18,53 @@ pub enum DataKey {
     CreatorAssets,
     /// To store the fee that Mixip collected from a contract
     FeeProfit,
+    /// To store the `PayoutPolicy` that requires several signers for large payouts
+    PayoutPolicy,
+    /// To store the payouts waiting for signers approval as `Map<u32, PendingPayout>`
+    PendingPayouts,
+    /// To store the identifier that will be assigned to the next pending payout
+    NextPayoutId,
+    /// To store the `Delegation` the creator granted to an agent or agency
+    Delegation,
+    /// To store the `Dispute` with the given id opened by one of the parties
+    Dispute(u32),
+    /// To store the evidence submitted to the dispute with the given id as `Vec<Evidence>`
+    DisputeEvidence(u32),
+    /// To store the identifier that will be assigned to the next dispute
+    NextDisputeId,
+    /// To store the ownership of the paid assets as `Map<Bytes, RightsRecord>`
+    RightsRecords,
+    /// To store the licenses granted over the paid assets as `Map<u64, License>`
+    Licenses,
+    /// To store the identifier that will be assigned to the next granted license
+    NextLicenseId,
+    /// To store the license renewal fees the creator can claim
+    LicenseRevenue,
+    /// To store the `Vec<AssetEvent>` history of the asset with the given id
+    AssetHistory(Bytes),
+    /// To store the `TemplateReference` the contract was created from
+    TemplateReference,
+    /// To store the hash of the `PaymentContractInfo` the creator signed
+    AcceptedTermsHash,
+    /// To store the log of the proposed amendments as `Vec<Amendment>`
+    Amendments,
+    /// To store the `CounterOffer` the creator made before signing
+    CounterOffer,
+    /// To store the total amount paid to the creator and their delegate
+    TotalPaid,
+    /// To store the `Settlement` proposed to terminate the contract
+    Settlement,
+    /// To store the `PayoutFunds` the company holds in the contract for the proposed settlement
+    SettlementFunds,
+    /// To store the `Vec<ContractEvent>` history of the contract
+    ContractHistory,
+    /// To store the funds the manager escrowed in the contract to pay the assets approved once their
+    /// review window is over
+    Escrow,
+    /// To store the `ReviewPolicy` that requires several reviewers to approve each asset
+    ReviewPolicy,
+    /// To store the reviewer votes of the given asset as `Map<Address, ReviewVote>`
+    ReviewVotes(Bytes),
 }
 
 #[contracttype]
@@ -26,4 +73,6 @@ pub enum ContractState {
     Active,
     Rejected,
     Finished,
+    /// The company cancelled the contract after it was signed
+    Cancelled,
 }
\ No newline at end of file
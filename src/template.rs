@@ -0,0 +1,107 @@
+//! Module Template
+//!
+//! Module that builds the contract info of the contracts created from a template published in a
+//! `TemplateRegistry` contract, and keeps the reference to that template.
+use crate::{
+    payment_contract_info::{ContractManager, LinkedContract, PaymentContractInfo, PaymentMethod},
+    storage_types::DataKey,
+};
+use soroban_sdk::{contractclient, contracttype, Address, Bytes, BytesN, Env};
+use soropay_types::ContractTemplate;
+
+/// Interface of the `TemplateRegistry` contract used by the payment contract, only the generated
+/// client is called
+#[allow(dead_code)]
+#[contractclient(name = "TemplateRegistryClient")]
+pub trait TemplateRegistry {
+    fn get_template(env: Env, publisher: Address, name: Bytes, version: u32) -> ContractTemplate;
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// Identifies a published version of a template
+pub struct TemplateReference {
+    /// The `TemplateRegistry` contract where the template was published
+    pub registry: BytesN<32>,
+    pub publisher: Address,
+    pub name: Bytes,
+    pub version: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The payment amount for each approved asset of a deal
+pub enum AssetPrice {
+    /// The default payment amount of the template
+    TemplateDefault,
+    Amount(i128),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[contracttype]
+/// The fields of a contract that aren't taken from its template
+pub struct ContractDeal {
+    pub contract_manager: ContractManager,
+    pub project_id: Bytes,
+    pub contract_name: Bytes,
+    pub payment_method: PaymentMethod,
+    pub creation_date: u64,
+    pub start_date: u64,
+    pub deadline: u64,
+    pub asset_payment_amount: AssetPrice,
+    pub asset_registry: LinkedContract,
+    pub project_registry: LinkedContract,
+    pub offer_expiry: u64,
+    pub budget: i128,
+}
+
+/// Builds the contract info of a deal made under the referenced template
+pub(crate) fn build_contract_info(
+    env: &Env,
+    reference: &TemplateReference,
+    deal: ContractDeal,
+) -> PaymentContractInfo {
+    let template = TemplateRegistryClient::new(env, &reference.registry).get_template(
+        &reference.publisher,
+        &reference.name,
+        &reference.version,
+    );
+    let terms = template.terms;
+    PaymentContractInfo {
+        contract_manager: deal.contract_manager,
+        company_id: terms.company_id,
+        project_id: deal.project_id,
+        contract_name: deal.contract_name,
+        payment_method: deal.payment_method,
+        asset_payment_amount: match deal.asset_payment_amount {
+            AssetPrice::TemplateDefault => terms.asset_payment_amount,
+            AssetPrice::Amount(amount) => amount,
+        },
+        creation_date: deal.creation_date,
+        start_date: deal.start_date,
+        deadline: deal.deadline,
+        scope_of_work: terms.scope_of_work,
+        rights_royalties: terms.rights_royalties,
+        payment_time: terms.payment_time,
+        contract_type: terms.contract_type,
+        arbitration_panel: terms.arbitration_panel,
+        dispute_response_window: terms.dispute_response_window,
+        asset_registry: deal.asset_registry,
+        license_terms: terms.license_terms,
+        project_registry: deal.project_registry,
+        offer_expiry: deal.offer_expiry,
+        budget: deal.budget,
+        kill_fee: terms.kill_fee,
+        review_window: terms.review_window,
+    }
+}
+
+pub(crate) fn write_template_reference(env: &Env, reference: &TemplateReference) {
+    env.storage().set(&DataKey::TemplateReference, reference)
+}
+
+pub(crate) fn read_template_reference(env: &Env) -> Option<TemplateReference> {
+    env.storage()
+        .get(&DataKey::TemplateReference)
+        .map(|reference| reference.unwrap())
+}
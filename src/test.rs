@@ -3,12 +3,37 @@
 extern crate std;
 
 use crate::{
-    asset::{Asset, AssetState},
-    payment_contract_info::{ContractManager, ContractType, PaymentContractInfo, PaymentMethod},
+    amendment::{AmendmentStatus, TermChange},
+    asset::{
+        Asset, AssetMetadata, AssetPreview, AssetReference, AssetState, AssetSubmission,
+        Dimensions, MediaExtent, MediaType,
+    },
+    delegation::{Delegation, DelegationScope},
+    dispute::{DisputeState, Ruling},
+    history::{AssetAction, ContractAction},
+    license::{LicenseOrigin, LicenseStatus},
+    multisig::{PayoutFunds, PayoutPolicy},
+    offer::CounterOfferTerms,
+    payment_contract_info::{
+        ArbitrationPanel, ContractManager, ContractType, KillFee, LicenseTerms, LinkedContract,
+        OfferedLicense, PaymentContractInfo, PaymentMethod,
+    },
+    quorum::{ReviewPolicy, ReviewVote},
+    rights::RightsTransfer,
     storage_types::ContractState,
+    template::{AssetPrice, ContractDeal, TemplateReference},
     PaymentContract, PaymentContractClient,
 };
-use soroban_sdk::{map, testutils::Address as _, Address, Bytes, BytesN, Env, IntoVal, Map};
+use soroban_sdk::{
+    map,
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, BytesN, Env, IntoVal, Map,
+};
+use soropay_asset_registry::{
+    AssetRegistry, AssetRegistryClient, ClaimArbitrator, ClaimResponse, ClaimStatus, RegistryConfig,
+};
+use soropay_project_registry::{ProjectRegistry, ProjectRegistryClient};
+use soropay_template_registry::{TemplateRegistry, TemplateRegistryClient, TemplateTerms};
 
 mod token_contract {
     soroban_sdk::contractimport!(file = "soroban_token_spec.wasm");
@@ -23,6 +48,57 @@ fn create_and_init_token_contract(
     (id, token)
 }
 
+fn create_asset_metadata(env: &Env, title: &str) -> AssetMetadata {
+    AssetMetadata {
+        title: title.into_val(env),
+        media_type: MediaType::Image,
+        extent: MediaExtent::Dimensions(Dimensions {
+            width: 1920,
+            height: 1080,
+            depth: 0,
+        }),
+        file_size: 2097152,
+        preview: AssetPreview::None,
+    }
+}
+
+fn create_license_terms(env: &Env) -> LicenseTerms {
+    LicenseTerms {
+        scope: "Web and social media".into_val(env),
+        territories: vec![env, "US".into_val(env), "CA".into_val(env)],
+        channels: 0b011,
+        exclusive: false,
+        duration: 31556926,
+        renewal_fee: 20,
+        sublicensable: true,
+        royalty_rate: 1000,
+    }
+}
+
+fn create_asset_registry(test: &PaymentContractTest, bond_amount: i128) -> BytesN<32> {
+    let PaymentMethod::Native(token_id) = test.payment_contract_info.payment_method.clone();
+    let registry_id = test.env.register_contract(None, AssetRegistry {});
+    AssetRegistryClient::new(&test.env, &registry_id).init_registry(&RegistryConfig {
+        admin: Address::random(&test.env),
+        arbitrator: ClaimArbitrator::Independent(test.arbitrator.clone()),
+        bond_token: token_id,
+        bond_amount,
+    });
+    registry_id
+}
+
+fn create_project_registry(test: &PaymentContractTest, total_budget: i128) -> BytesN<32> {
+    let PaymentMethod::Native(token_id) = test.payment_contract_info.payment_method.clone();
+    let registry_id = test.env.register_contract(None, ProjectRegistry {});
+    ProjectRegistryClient::new(&test.env, &registry_id).create_project(
+        &test.payment_contract_info.contract_manager.address,
+        &test.payment_contract_info.project_id,
+        &token_id,
+        &total_budget,
+    );
+    registry_id
+}
+
 fn create_payment_contract(
     e: &Env,
     payment_contract_info: &PaymentContractInfo,
@@ -36,9 +112,12 @@ fn create_payment_contract(
 
 struct PaymentContractTest {
     env: Env,
+    token_admin: Address,
     payment_contract_info: PaymentContractInfo,
     creator_address: Address,
-    assets: Map<Bytes, Bytes>,
+    arbitrator: Address,
+    assets: Map<Bytes, AssetSubmission>,
+    asset_hashes: Map<Bytes, BytesN<32>>,
     token_client: token_contract::Client,
 }
 
@@ -48,6 +127,7 @@ impl PaymentContractTest {
         let token_admin = Address::random(&env);
         let contract_manager_address = Address::random(&env);
         let creator_address = Address::random(&env);
+        let arbitrator = Address::random(&env);
         let company_id: Bytes = "ID-001".into_val(&env);
         let project_id: Bytes = "ID-001".into_val(&env);
         let contract_name: Bytes = "Test Contract Name".into_val(&env);
@@ -73,17 +153,60 @@ impl PaymentContractTest {
             start_date: 1682003560,
             scope_of_work: "scope_of_work text".into_val(&env),
             rights_royalties: "rights_royalties text".into_val(&env),
+            arbitration_panel: ArbitrationPanel {
+                arbitrators: vec![&env, arbitrator.clone()],
+                voting_period: 604800,
+                arbitration_fee: 0,
+            },
+            dispute_response_window: 604800,
+            asset_registry: LinkedContract::None,
+            license_terms: OfferedLicense::None,
+            project_registry: LinkedContract::None,
+            offer_expiry: 0,
+            budget: 20,
+            kill_fee: KillFee::None,
+            review_window: 0,
         };
-        let assets: Map<Bytes, Bytes> = map![
+        let asset_hashes: Map<Bytes, BytesN<32>> = map![
+            &env,
+            (
+                "ASSET-ID-1".into_val(&env),
+                BytesN::from_array(&env, &[1; 32])
+            ),
+            (
+                "ASSET-ID-2".into_val(&env),
+                BytesN::from_array(&env, &[2; 32])
+            ),
+        ];
+        let assets: Map<Bytes, AssetSubmission> = map![
             &env,
-            ("ASSET-ID-1".into_val(&env), "asset-1-url".into_val(&env)),
-            ("ASSET-ID-2".into_val(&env), "asset-2-url".into_val(&env)),
+            (
+                "ASSET-ID-1".into_val(&env),
+                AssetSubmission {
+                    asset_url: "asset-1-url".into_val(&env),
+                    content_hash: BytesN::from_array(&env, &[1; 32]),
+                    metadata: create_asset_metadata(&env, "Asset 1"),
+                    parent: AssetReference::Original,
+                }
+            ),
+            (
+                "ASSET-ID-2".into_val(&env),
+                AssetSubmission {
+                    asset_url: "asset-2-url".into_val(&env),
+                    content_hash: BytesN::from_array(&env, &[2; 32]),
+                    metadata: create_asset_metadata(&env, "Asset 2"),
+                    parent: AssetReference::Original,
+                }
+            ),
         ];
         PaymentContractTest {
             env,
+            token_admin,
             payment_contract_info,
             creator_address,
+            arbitrator,
             assets,
+            asset_hashes,
             token_client,
         }
     }
@@ -103,12 +226,16 @@ fn test_successful_execution_of_wallet_capabilities_upon_approval() {
         test.payment_contract_info
     );
 
-    payment_contract.sign_contract(&1681977600);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
 
-    payment_contract.submit_asset(&test.assets, &1683158399);
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
     assert_eq!(payment_contract.get_submitted_assets().len(), 2);
 
-    payment_contract.approve_asset(&test.assets.keys(), &1677953357);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
     let asset: Asset = payment_contract
         .get_submitted_assets()
         .values()
@@ -132,12 +259,16 @@ fn test_successful_execution_of_wallet_capabilities_with_payment_time() {
     let payment_contract =
         create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
 
-    payment_contract.sign_contract(&1681977600);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
 
-    payment_contract.submit_asset(&test.assets, &1683158399);
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
     assert_eq!(payment_contract.get_submitted_assets().len(), 2);
 
-    payment_contract.approve_asset(&test.assets.keys(), &1677953357);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
     let mut asset: Asset = payment_contract
         .get_submitted_assets()
         .values()
@@ -170,10 +301,14 @@ fn test_successful_execution_of_wallet_capabilities_on_prepayment() {
     let payment_contract =
         create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
 
-    payment_contract.sign_contract(&1681977600);
-    payment_contract.submit_asset(&test.assets, &1683158399);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
 
-    payment_contract.approve_asset(&test.assets.keys(), &1677953357);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
     let mut asset: Asset = payment_contract
         .get_submitted_assets()
         .values()
@@ -219,8 +354,16 @@ fn test_accepting_and_already_accepted_contract() {
         &test.creator_address,
     );
 
-    payment_contract.sign_contract(&1681977600);
-    payment_contract.sign_contract(&1681999200);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681999200,
+    );
 }
 
 #[test]
@@ -234,7 +377,7 @@ fn test_submit_assets_when_contract_not_active() {
         &test.creator_address,
     );
 
-    payment_contract.submit_asset(&test.assets, &1683158399);
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
 }
 
 #[test]
@@ -248,8 +391,12 @@ fn test_approve_assets_when_no_assets_in_contract() {
         &test.creator_address,
     );
 
-    payment_contract.sign_contract(&1681977600);
-    payment_contract.approve_asset(&test.assets.keys(), &1677953357);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
 }
 
 #[test]
@@ -263,8 +410,12 @@ fn test_execute_payment_when_no_approved_assets() {
     let payment_contract =
         create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
 
-    payment_contract.sign_contract(&1681977600);
-    payment_contract.submit_asset(&test.assets, &1683158399);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
     payment_contract.execute_payment(&payment_date, &Option::Some(contract_manager_address));
 }
 
@@ -287,6 +438,2296 @@ fn test_get_submitted_assets_when_no_submitted_assets() {
     let payment_contract =
         create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
 
-    payment_contract.sign_contract(&1681977600);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
     payment_contract.get_submitted_assets();
+}
+
+#[test]
+fn test_large_payout_is_executed_after_required_approvals() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let first_signer = Address::random(&test.env);
+    let second_signer = Address::random(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.set_payout_policy(&PayoutPolicy {
+        threshold_amount: 5,
+        signers: vec![&test.env, first_signer.clone(), second_signer.clone()],
+        required_approvals: 2,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
+    let mut asset: Asset = payment_contract
+        .get_submitted_assets()
+        .values()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(asset.state, AssetState::PaymentPending);
+    assert_eq!(payment_contract.get_pending_payouts().len(), 1);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 990);
+
+    payment_contract.approve_payout(&first_signer, &0);
+    assert_eq!(test.token_client.balance(&test.creator_address), 0);
+
+    payment_contract.approve_payout(&second_signer, &0);
+    asset = payment_contract
+        .get_submitted_assets()
+        .values()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(asset.state, AssetState::Paid);
+    assert_eq!(payment_contract.get_pending_payouts().len(), 0);
+    assert_eq!(test.token_client.balance(&test.creator_address), 10);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(8))")]
+fn test_approve_payout_with_an_address_outside_the_signers_group() {
+    let test = PaymentContractTest::setup();
+    let signer = Address::random(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.set_payout_policy(&PayoutPolicy {
+        threshold_amount: 5,
+        signers: vec![&test.env, signer],
+        required_approvals: 1,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
+    payment_contract.approve_payout(&Address::random(&test.env), &0);
+}
+
+#[test]
+fn test_delegate_signs_and_submits_and_receives_commission() {
+    let test = PaymentContractTest::setup();
+    let delegate = Address::random(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.authorize_delegate(&Delegation {
+        delegate: delegate.clone(),
+        scope: DelegationScope::SignAndSubmit,
+        expiry: 1684546903,
+        commission_rate: 2000,
+    });
+
+    payment_contract.sign_contract(&delegate, &payment_contract.get_terms_hash(), &1681977600);
+    payment_contract.submit_asset(&delegate, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
+
+    assert_eq!(test.token_client.balance(&test.creator_address), 8);
+    assert_eq!(test.token_client.balance(&delegate), 2);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(12))")]
+fn test_delegate_signs_outside_of_its_scope() {
+    let test = PaymentContractTest::setup();
+    let delegate = Address::random(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.authorize_delegate(&Delegation {
+        delegate: delegate.clone(),
+        scope: DelegationScope::Submit,
+        expiry: 1684546903,
+        commission_rate: 0,
+    });
+
+    payment_contract.sign_contract(&delegate, &payment_contract.get_terms_hash(), &1681977600);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(12))")]
+fn test_delegate_submits_after_delegation_expiry() {
+    let test = PaymentContractTest::setup();
+    let delegate = Address::random(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.authorize_delegate(&Delegation {
+        delegate: delegate.clone(),
+        scope: DelegationScope::SignAndSubmit,
+        expiry: 1682000000,
+        commission_rate: 0,
+    });
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1681977600);
+    payment_contract.sign_contract(&delegate, &payment_contract.get_terms_hash(), &1681977600);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683158399);
+    payment_contract.submit_asset(&delegate, &test.assets);
+}
+
+#[test]
+fn test_expired_delegation_takes_no_commission() {
+    let test = PaymentContractTest::setup();
+    let delegate = Address::random(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.authorize_delegate(&Delegation {
+        delegate: delegate.clone(),
+        scope: DelegationScope::SignAndSubmit,
+        expiry: 1682000000,
+        commission_rate: 2000,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+
+    assert_eq!(test.token_client.balance(&test.creator_address), 10);
+    assert_eq!(test.token_client.balance(&delegate), 0);
+}
+
+#[test]
+fn test_update_creator_revokes_the_delegation_of_the_previous_creator() {
+    let test = PaymentContractTest::setup();
+    let new_creator_address = Address::random(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.authorize_delegate(&Delegation {
+        delegate: Address::random(&test.env),
+        scope: DelegationScope::SignAndSubmit,
+        expiry: 1684546903,
+        commission_rate: 2000,
+    });
+
+    payment_contract.update_creator(&new_creator_address);
+    payment_contract.sign_contract(
+        &new_creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&new_creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
+
+    assert_eq!(payment_contract.get_delegation(), None);
+    assert_eq!(test.token_client.balance(&new_creator_address), 10);
+}
+
+#[test]
+fn test_rejected_assets_are_approved_by_the_arbitrator_ruling() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 2629743_u64;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    assert_eq!(payment_contract.get_dispute(&0).state, DisputeState::Open);
+
+    payment_contract.vote_on_dispute(&test.arbitrator, &Ruling::ForceApproval);
+    let asset: Asset = payment_contract
+        .get_submitted_assets()
+        .values()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(asset.state, AssetState::Approved);
+    assert_eq!(
+        payment_contract.get_dispute(&0).ruling,
+        Ruling::ForceApproval
+    );
+    assert_eq!(
+        payment_contract.get_dispute(&0).state,
+        DisputeState::Resolved
+    );
+}
+
+#[test]
+fn test_force_approval_ruling_is_paid_when_enforced() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.vote_on_dispute(&test.arbitrator, &Ruling::ForceApproval);
+    assert_eq!(payment_contract.get_dispute(&0).state, DisputeState::Ruled);
+
+    payment_contract.enforce_ruling(&0);
+
+    assert_eq!(test.token_client.balance(&test.creator_address), 10);
+    assert_eq!(
+        payment_contract.get_dispute(&0).state,
+        DisputeState::Resolved
+    );
+}
+
+#[test]
+fn test_partial_payment_ruling_pays_the_creator() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.vote_on_dispute(&test.arbitrator, &Ruling::PartialPayment(4));
+    payment_contract.enforce_ruling(&0);
+    let asset: Asset = payment_contract
+        .get_submitted_assets()
+        .values()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(test.token_client.balance(&test.creator_address), 4);
+    assert_eq!(asset.state, AssetState::Rejected);
+    assert_eq!(
+        payment_contract.get_dispute(&0).state,
+        DisputeState::Resolved
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(18))")]
+fn test_partial_payment_ruling_of_a_negative_amount() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.vote_on_dispute(&test.arbitrator, &Ruling::PartialPayment(-4));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(5))")]
+fn test_execute_payment_of_assets_frozen_by_a_dispute() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 2629743_u64;
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_date = payment_contract_info.deadline + payment_contract_info.payment_time;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &contract_manager_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.execute_payment(&payment_date, &Option::Some(contract_manager_address));
+}
+
+#[test]
+fn test_dispute_is_ruled_by_default_when_respondent_submits_no_evidence() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 2629743_u64;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683300000);
+    payment_contract.add_evidence(
+        &test.creator_address,
+        &BytesN::from_array(&test.env, &[2; 32]),
+        &"ipfs://evidence".into_val(&test.env),
+    );
+    assert_eq!(payment_contract.get_dispute_evidence(&0).len(), 2);
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683900000);
+    payment_contract.resolve_dispute_by_default(&test.arbitrator);
+    let asset: Asset = payment_contract
+        .get_submitted_assets()
+        .values()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(asset.state, AssetState::Approved);
+}
+
+#[test]
+fn test_new_dispute_keeps_the_evidence_of_previous_disputes() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 2629743_u64;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    let first_dispute_id = payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.vote_on_dispute(&test.arbitrator, &Ruling::UpholdRejection);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683400000);
+    let second_dispute_id = payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[3; 32]),
+    );
+
+    assert_eq!(first_dispute_id, 0);
+    assert_eq!(second_dispute_id, 1);
+    assert_eq!(
+        payment_contract.get_dispute(&first_dispute_id).state,
+        DisputeState::Resolved
+    );
+    assert_eq!(
+        payment_contract.get_dispute(&second_dispute_id).state,
+        DisputeState::Open
+    );
+    assert_eq!(
+        payment_contract
+            .get_dispute_evidence(&first_dispute_id)
+            .get(0)
+            .unwrap()
+            .unwrap()
+            .content_hash,
+        BytesN::from_array(&test.env, &[1; 32])
+    );
+    assert_eq!(
+        payment_contract
+            .get_dispute_evidence(&second_dispute_id)
+            .get(0)
+            .unwrap()
+            .unwrap()
+            .content_hash,
+        BytesN::from_array(&test.env, &[3; 32])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(20))")]
+fn test_add_evidence_after_response_window() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683900000);
+    payment_contract.add_evidence(
+        &contract_manager_address,
+        &BytesN::from_array(&test.env, &[2; 32]),
+        &"ipfs://evidence".into_val(&test.env),
+    );
+}
+
+#[test]
+fn test_panel_majority_ruling_is_funded_by_the_losing_side_deposit() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let first_arbitrator = Address::random(&test.env);
+    let second_arbitrator = Address::random(&test.env);
+    let third_arbitrator = Address::random(&test.env);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 2629743_u64;
+    payment_contract_info.arbitration_panel = ArbitrationPanel {
+        arbitrators: vec![
+            &test.env,
+            first_arbitrator.clone(),
+            second_arbitrator.clone(),
+            third_arbitrator.clone(),
+        ],
+        voting_period: 604800,
+        arbitration_fee: 2,
+    };
+    test.token_client
+        .mint(&test.token_admin, &test.creator_address, &6_i128);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.deposit_arbitration_fee(&contract_manager_address);
+    payment_contract.vote_on_dispute(&first_arbitrator, &Ruling::ForceApproval);
+    assert_eq!(payment_contract.get_dispute(&0).state, DisputeState::Open);
+
+    payment_contract.vote_on_dispute(&second_arbitrator, &Ruling::ForceApproval);
+
+    assert_eq!(
+        payment_contract.get_dispute(&0).state,
+        DisputeState::Resolved
+    );
+    assert_eq!(test.token_client.balance(&first_arbitrator), 2);
+    assert_eq!(test.token_client.balance(&second_arbitrator), 2);
+    assert_eq!(test.token_client.balance(&third_arbitrator), 0);
+    assert_eq!(test.token_client.balance(&test.creator_address), 6);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 996);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(23))")]
+fn test_vote_on_dispute_with_an_address_outside_the_panel() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.vote_on_dispute(&Address::random(&test.env), &Ruling::ForceApproval);
+}
+
+#[test]
+fn test_dispute_vote_without_majority_is_dismissed() {
+    let test = PaymentContractTest::setup();
+    let first_arbitrator = Address::random(&test.env);
+    let second_arbitrator = Address::random(&test.env);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.arbitration_panel = ArbitrationPanel {
+        arbitrators: vec![
+            &test.env,
+            first_arbitrator.clone(),
+            second_arbitrator.clone(),
+        ],
+        voting_period: 604800,
+        arbitration_fee: 0,
+    };
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&test.assets.keys());
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.open_dispute(
+        &test.creator_address,
+        &test.assets.keys(),
+        &BytesN::from_array(&test.env, &[1; 32]),
+    );
+    payment_contract.vote_on_dispute(&first_arbitrator, &Ruling::ForceApproval);
+    payment_contract.vote_on_dispute(&second_arbitrator, &Ruling::UpholdRejection);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1684409601);
+    payment_contract.close_dispute_vote();
+
+    assert_eq!(
+        payment_contract.get_dispute(&0).state,
+        DisputeState::Dismissed
+    );
+    assert_eq!(payment_contract.get_dispute(&0).ruling, Ruling::Pending);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(28))")]
+fn test_approve_asset_with_a_different_content_hash() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    let approved_hashes: Map<Bytes, BytesN<32>> = map![
+        &test.env,
+        (
+            "ASSET-ID-1".into_val(&test.env),
+            BytesN::from_array(&test.env, &[9; 32])
+        ),
+    ];
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&approved_hashes, &1677953357);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(27))")]
+fn test_resubmit_an_approved_asset() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 2629743_u64;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+}
+
+#[test]
+fn test_update_the_url_of_an_approved_asset_keeps_its_content_hash() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 2629743_u64;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+    let new_url: Bytes = "asset-1-new-url".into_val(&test.env);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1677953357);
+    payment_contract.update_asset_url(&test.creator_address, &asset_id, &new_url);
+    let asset: Asset = payment_contract
+        .get_submitted_assets()
+        .get(asset_id)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(asset.asset_url, new_url);
+    assert_eq!(asset.content_hash, BytesN::from_array(&test.env, &[1; 32]));
+    assert_eq!(asset.state, AssetState::Approved);
+}
+
+#[test]
+fn test_submitted_works_are_registered_in_the_asset_registry() {
+    let test = PaymentContractTest::setup();
+    let registry_id = create_asset_registry(&test, 0);
+    let registry = AssetRegistryClient::new(&test.env, &registry_id);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.asset_registry = LinkedContract::Id(registry_id);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    registry.set_trusted_contract(
+        &Address::from_contract_id(&test.env, &payment_contract.contract_id),
+        &true,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    let record = registry
+        .verify_authorship(&BytesN::from_array(&test.env, &[1; 32]))
+        .unwrap();
+
+    assert_eq!(record.creator, test.creator_address);
+    assert_eq!(
+        registry.verify_authorship(&BytesN::from_array(&test.env, &[9; 32])),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_submit_asset_to_a_registry_that_does_not_trust_the_contract() {
+    let test = PaymentContractTest::setup();
+    let registry_id = create_asset_registry(&test, 0);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.asset_registry = LinkedContract::Id(registry_id);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(29))")]
+fn test_submit_asset_without_title() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    let assets: Map<Bytes, AssetSubmission> = map![
+        &test.env,
+        (
+            "ASSET-ID-1".into_val(&test.env),
+            AssetSubmission {
+                asset_url: "asset-1-url".into_val(&test.env),
+                content_hash: BytesN::from_array(&test.env, &[1; 32]),
+                metadata: create_asset_metadata(&test.env, ""),
+                parent: AssetReference::Original,
+            }
+        ),
+    ];
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &assets);
+}
+
+#[test]
+fn test_paid_assets_assign_rights_to_the_company() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let record = payment_contract.get_rights_record(&"ASSET-ID-1".into_val(&test.env));
+
+    assert_eq!(record.rights_holder, contract_manager_address);
+    assert_eq!(record.transfer, RightsTransfer::Assignment);
+    assert_eq!(record.assignment_date, 1683200000);
+}
+
+#[test]
+fn test_paid_assets_of_a_licensing_contract_keep_the_creator_as_owner() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.contract_type = ContractType::Licensing;
+    payment_contract_info.license_terms = OfferedLicense::Terms(create_license_terms(&test.env));
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let record = payment_contract.get_rights_record(&"ASSET-ID-1".into_val(&test.env));
+
+    assert_eq!(record.rights_holder, test.creator_address);
+    match record.transfer {
+        RightsTransfer::License(license_id) => assert_eq!(
+            payment_contract.get_license(&license_id).licensee,
+            contract_manager_address
+        ),
+        RightsTransfer::Assignment => panic!("the asset rights were assigned"),
+    }
+}
+
+#[test]
+fn test_paid_assets_of_a_licensing_contract_grant_a_license() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.contract_type = ContractType::Licensing;
+    payment_contract_info.license_terms = OfferedLicense::Terms(create_license_terms(&test.env));
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let license_id = match payment_contract.get_rights_record(&asset_id).transfer {
+        RightsTransfer::License(license_id) => license_id,
+        RightsTransfer::Assignment => panic!("the asset rights were assigned"),
+    };
+    let license = payment_contract.get_license(&license_id);
+
+    assert_eq!(license.licensee, contract_manager_address);
+    assert_eq!(license.asset_id, asset_id);
+    assert_eq!(license.start_date, 1683200000);
+    assert_eq!(license.end_date, 1683200000 + 31556926);
+    assert!(payment_contract.is_licensed(&asset_id, &contract_manager_address, &1690000000));
+    assert!(!payment_contract.is_licensed(&asset_id, &contract_manager_address, &1720000000));
+    assert!(!payment_contract.is_licensed(&asset_id, &test.creator_address, &1690000000));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(31))")]
+fn test_initialize_a_licensing_contract_without_license_terms() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.contract_type = ContractType::Licensing;
+
+    create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+}
+
+#[test]
+fn test_license_resale_and_sublicense_pay_royalties_to_the_creator() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let buyer = Address::random(&test.env);
+    let sublicensee = Address::random(&test.env);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.contract_type = ContractType::Licensing;
+    payment_contract_info.license_terms = OfferedLicense::Terms(create_license_terms(&test.env));
+    test.token_client.mint(&test.token_admin, &buyer, &100_i128);
+    test.token_client
+        .mint(&test.token_admin, &sublicensee, &50_i128);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let license_id = match payment_contract.get_rights_record(&asset_id).transfer {
+        RightsTransfer::License(license_id) => license_id,
+        RightsTransfer::Assignment => panic!("the asset rights were assigned"),
+    };
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1684000000);
+    payment_contract.transfer_license(&license_id, &contract_manager_address, &buyer, &100);
+    assert_eq!(payment_contract.get_license(&license_id).licensee, buyer);
+    assert_eq!(test.token_client.balance(&test.creator_address), 20);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 1080);
+
+    let sublicense_id = payment_contract.issue_sublicense(&license_id, &buyer, &sublicensee, &50);
+    let sublicense = payment_contract.get_license(&sublicense_id);
+
+    assert_eq!(sublicense.origin, LicenseOrigin::Sublicense(license_id));
+    assert!(!sublicense.sublicensable);
+    assert_eq!(test.token_client.balance(&test.creator_address), 25);
+    assert_eq!(test.token_client.balance(&buyer), 45);
+    assert!(payment_contract.is_licensed(&asset_id, &sublicensee, &1690000000));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(33))")]
+fn test_transfer_a_license_not_held_by_the_caller() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.contract_type = ContractType::Licensing;
+    payment_contract_info.license_terms = OfferedLicense::Terms(create_license_terms(&test.env));
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    payment_contract.transfer_license(
+        &0,
+        &Address::random(&test.env),
+        &Address::random(&test.env),
+        &0,
+    );
+}
+
+#[test]
+fn test_license_expires_and_is_renewed_by_paying_the_renewal_fee() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.contract_type = ContractType::Licensing;
+    payment_contract_info.license_terms = OfferedLicense::Terms(create_license_terms(&test.env));
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+    let territory: Bytes = "US".into_val(&test.env);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let license_id = match payment_contract.get_rights_record(&asset_id).transfer {
+        RightsTransfer::License(license_id) => license_id,
+        RightsTransfer::Assignment => panic!("the asset rights were assigned"),
+    };
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1684000000);
+    assert!(payment_contract.is_licensed_for_use(
+        &asset_id,
+        &contract_manager_address,
+        &territory,
+        &0b001
+    ));
+    assert!(!payment_contract.is_licensed_for_use(
+        &asset_id,
+        &contract_manager_address,
+        &"FR".into_val(&test.env),
+        &0b001
+    ));
+    assert!(!payment_contract.is_licensed_for_use(
+        &asset_id,
+        &contract_manager_address,
+        &territory,
+        &0b100
+    ));
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1720000000);
+    assert_eq!(
+        payment_contract.get_license(&license_id).status,
+        LicenseStatus::Expired
+    );
+
+    payment_contract.renew_license(&license_id, &contract_manager_address);
+    let license = payment_contract.get_license(&license_id);
+
+    assert_eq!(license.status, LicenseStatus::Active);
+    assert_eq!(license.end_date, 1720000000 + 31556926);
+    assert_eq!(payment_contract.claim_license_revenue(), 20);
+    assert_eq!(test.token_client.balance(&test.creator_address), 30);
+}
+
+#[test]
+fn test_asset_history_records_every_change_of_the_asset() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+    let new_url: Bytes = "https://assets.mixip.io/asset-1-v2.png".into_val(&test.env);
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683158399);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.update_asset_url(&test.creator_address, &asset_id, &new_url);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683200000);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let history = payment_contract.get_asset_history(&asset_id);
+    let expected_actions = vec![
+        &test.env,
+        AssetAction::Submitted(BytesN::from_array(&test.env, &[1; 32])),
+        AssetAction::UrlUpdated(new_url),
+        AssetAction::Approved,
+        AssetAction::Paid,
+        AssetAction::RightsAssigned(contract_manager_address.clone()),
+    ];
+
+    assert_eq!(history.len(), expected_actions.len());
+    for (event, action) in history.iter().zip(expected_actions.iter()) {
+        assert_eq!(event.unwrap().action, action.unwrap());
+    }
+    assert_eq!(
+        history.get_unchecked(0).unwrap().actor,
+        test.creator_address
+    );
+    assert_eq!(history.get_unchecked(0).unwrap().timestamp, 1683158399);
+    assert_eq!(
+        history.get_unchecked(4).unwrap().actor,
+        contract_manager_address
+    );
+    assert_eq!(history.get_unchecked(4).unwrap().timestamp, 1683200000);
+}
+
+#[test]
+fn test_asset_history_records_the_rejection() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    let asset_id: Bytes = "ASSET-ID-2".into_val(&test.env);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.reject_asset(&vec![&test.env, asset_id.clone()]);
+    let history = payment_contract.get_asset_history(&asset_id);
+
+    assert_eq!(history.len(), 2);
+    let rejection = history.get_unchecked(1).unwrap();
+    assert_eq!(rejection.action, AssetAction::Rejected);
+    assert_eq!(rejection.actor, contract_manager_address);
+}
+
+fn create_derivative_submission(
+    env: &Env,
+    title: &str,
+    content_hash: &BytesN<32>,
+    parent: AssetReference,
+) -> AssetSubmission {
+    AssetSubmission {
+        asset_url: "derivative-url".into_val(env),
+        content_hash: content_hash.clone(),
+        metadata: create_asset_metadata(env, title),
+        parent,
+    }
+}
+
+#[test]
+fn test_derivative_works_are_linked_to_their_parent_in_the_registry() {
+    let test = PaymentContractTest::setup();
+    let registry_id = create_asset_registry(&test, 0);
+    let registry = AssetRegistryClient::new(&test.env, &registry_id);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.asset_registry = LinkedContract::Id(registry_id);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    registry.set_trusted_contract(
+        &Address::from_contract_id(&test.env, &payment_contract.contract_id),
+        &true,
+    );
+    let original_hash = BytesN::from_array(&test.env, &[1; 32]);
+    let remix_hash = BytesN::from_array(&test.env, &[3; 32]);
+    let translation_hash = BytesN::from_array(&test.env, &[4; 32]);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(
+        &map![
+            &test.env,
+            ("ASSET-ID-1".into_val(&test.env), original_hash.clone())
+        ],
+        &1683200000,
+    );
+    payment_contract.submit_asset(
+        &test.creator_address,
+        &map![
+            &test.env,
+            (
+                "ASSET-ID-3".into_val(&test.env),
+                create_derivative_submission(
+                    &test.env,
+                    "Remix",
+                    &remix_hash,
+                    AssetReference::Local("ASSET-ID-1".into_val(&test.env)),
+                )
+            )
+        ],
+    );
+    payment_contract.submit_asset(
+        &test.creator_address,
+        &map![
+            &test.env,
+            (
+                "ASSET-ID-4".into_val(&test.env),
+                create_derivative_submission(
+                    &test.env,
+                    "Translation",
+                    &translation_hash,
+                    AssetReference::ContentHash(remix_hash.clone()),
+                )
+            )
+        ],
+    );
+
+    assert_eq!(
+        registry.get_ancestry(&translation_hash),
+        vec![&test.env, remix_hash.clone(), original_hash.clone()]
+    );
+    assert_eq!(
+        registry.get_descendants(&original_hash),
+        vec![&test.env, remix_hash, translation_hash]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(37))")]
+fn test_submit_a_derivative_of_an_asset_not_approved() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.submit_asset(
+        &test.creator_address,
+        &map![
+            &test.env,
+            (
+                "ASSET-ID-3".into_val(&test.env),
+                create_derivative_submission(
+                    &test.env,
+                    "Remix",
+                    &BytesN::from_array(&test.env, &[3; 32]),
+                    AssetReference::Local("ASSET-ID-2".into_val(&test.env)),
+                )
+            )
+        ],
+    );
+}
+
+#[test]
+fn test_upheld_infringement_claim_flags_the_work_and_freezes_its_payment() {
+    let test = PaymentContractTest::setup();
+    let claimant = Address::random(&test.env);
+    let registry_id = create_asset_registry(&test, 50);
+    let registry = AssetRegistryClient::new(&test.env, &registry_id);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.asset_registry = LinkedContract::Id(registry_id);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    registry.set_trusted_contract(
+        &Address::from_contract_id(&test.env, &payment_contract.contract_id),
+        &true,
+    );
+    let infringing_hash = BytesN::from_array(&test.env, &[1; 32]);
+    test.token_client
+        .mint(&test.token_admin, &claimant, &100_i128);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    let claim_id = registry.file_claim(
+        &claimant,
+        &infringing_hash,
+        &BytesN::from_array(&test.env, &[7; 32]),
+    );
+    assert_eq!(test.token_client.balance(&claimant), 50);
+
+    registry.respond_to_claim(&claim_id, &BytesN::from_array(&test.env, &[8; 32]));
+    registry.rule_on_claim(&test.arbitrator, &claim_id, &true);
+    let claim = registry.get_claim(&claim_id);
+
+    assert_eq!(claim.status, ClaimStatus::Upheld);
+    assert_eq!(
+        claim.response,
+        ClaimResponse::Answered(BytesN::from_array(&test.env, &[8; 32]))
+    );
+    assert!(registry.is_flagged(&infringing_hash));
+    assert_eq!(test.token_client.balance(&claimant), 100);
+
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let assets = payment_contract.get_submitted_assets();
+
+    assert_eq!(
+        assets
+            .get("ASSET-ID-1".into_val(&test.env))
+            .unwrap()
+            .unwrap()
+            .state,
+        AssetState::Approved
+    );
+    assert_eq!(
+        assets
+            .get("ASSET-ID-2".into_val(&test.env))
+            .unwrap()
+            .unwrap()
+            .state,
+        AssetState::Paid
+    );
+    assert_eq!(test.token_client.balance(&test.creator_address), 5);
+}
+
+#[test]
+fn test_payments_are_drawn_from_the_project_budget() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let project_id = test.payment_contract_info.project_id.clone();
+    let registry_id = create_project_registry(&test, 100);
+    let registry = ProjectRegistryClient::new(&test.env, &registry_id);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.project_registry = LinkedContract::Id(registry_id);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let payment_contract_address =
+        Address::from_contract_id(&test.env, &payment_contract.contract_id);
+
+    registry.commit_budget(&project_id, &payment_contract_address, &30);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let report = registry.get_budget_report(&project_id);
+
+    assert_eq!(test.token_client.balance(&test.creator_address), 10);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 900);
+    assert_eq!(report.total_budget, 100);
+    assert_eq!(report.committed, 30);
+    assert_eq!(report.paid, 10);
+    assert_eq!(report.remaining, 70);
+}
+
+#[test]
+fn test_large_payout_holds_the_project_budget_apart() {
+    let test = PaymentContractTest::setup();
+    let project_id = test.payment_contract_info.project_id.clone();
+    let signer = Address::random(&test.env);
+    let registry_id = create_project_registry(&test, 100);
+    let registry = ProjectRegistryClient::new(&test.env, &registry_id);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.project_registry = LinkedContract::Id(registry_id);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let payment_contract_address =
+        Address::from_contract_id(&test.env, &payment_contract.contract_id);
+    payment_contract.set_payout_policy(&PayoutPolicy {
+        threshold_amount: 5,
+        signers: vec![&test.env, signer.clone()],
+        required_approvals: 1,
+    });
+
+    registry.commit_budget(&project_id, &payment_contract_address, &30);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    let payout = payment_contract
+        .get_pending_payouts()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(payout.funds, PayoutFunds::Project);
+    assert_eq!(test.token_client.balance(&payment_contract_address), 10);
+    assert_eq!(registry.get_budget_report(&project_id).paid, 10);
+
+    payment_contract.approve_payout(&signer, &0);
+
+    assert_eq!(test.token_client.balance(&test.creator_address), 10);
+    assert_eq!(test.token_client.balance(&payment_contract_address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_payment_above_the_budget_committed_to_the_contract() {
+    let test = PaymentContractTest::setup();
+    let project_id = test.payment_contract_info.project_id.clone();
+    let registry_id = create_project_registry(&test, 100);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.project_registry = LinkedContract::Id(registry_id.clone());
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    ProjectRegistryClient::new(&test.env, &registry_id).commit_budget(
+        &project_id,
+        &Address::from_contract_id(&test.env, &payment_contract.contract_id),
+        &5,
+    );
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+}
+
+fn create_template_terms(test: &PaymentContractTest, scope_of_work: &str) -> TemplateTerms {
+    TemplateTerms {
+        company_id: test.payment_contract_info.company_id.clone(),
+        scope_of_work: scope_of_work.into_val(&test.env),
+        rights_royalties: test.payment_contract_info.rights_royalties.clone(),
+        contract_type: ContractType::FixedPrice,
+        asset_payment_amount: 5,
+        payment_time: 0,
+        arbitration_panel: test.payment_contract_info.arbitration_panel.clone(),
+        dispute_response_window: 604800,
+        license_terms: OfferedLicense::None,
+        kill_fee: KillFee::Flat(10),
+        review_window: 0,
+    }
+}
+
+fn create_contract_deal(test: &PaymentContractTest) -> ContractDeal {
+    ContractDeal {
+        contract_manager: test.payment_contract_info.contract_manager.clone(),
+        project_id: "ID-002".into_val(&test.env),
+        contract_name: "Contract from template".into_val(&test.env),
+        payment_method: test.payment_contract_info.payment_method.clone(),
+        creation_date: 1681917160,
+        start_date: 1682003560,
+        deadline: 1684546903,
+        asset_payment_amount: AssetPrice::Amount(8),
+        asset_registry: LinkedContract::None,
+        project_registry: LinkedContract::None,
+        offer_expiry: 0,
+        budget: 40,
+    }
+}
+
+#[test]
+fn test_initialize_a_contract_from_a_template_version() {
+    let test = PaymentContractTest::setup();
+    let publisher = Address::random(&test.env);
+    let name: Bytes = "Illustration".into_val(&test.env);
+    let registry_id = test.env.register_contract(None, TemplateRegistry {});
+    let registry = TemplateRegistryClient::new(&test.env, &registry_id);
+    let payment_contract = PaymentContractClient::new(
+        &test.env,
+        &test.env.register_contract(None, PaymentContract {}),
+    );
+
+    registry.publish_template(
+        &publisher,
+        &name,
+        &create_template_terms(&test, "First scope"),
+    );
+    let first_version = registry.get_template(&publisher, &name, &1);
+    registry.publish_template(
+        &publisher,
+        &name,
+        &create_template_terms(&test, "Second scope"),
+    );
+    let template = TemplateReference {
+        registry: registry_id,
+        publisher: publisher.clone(),
+        name: name.clone(),
+        version: 1,
+    };
+    payment_contract.initialize_from_template(
+        &template,
+        &create_contract_deal(&test),
+        &test.creator_address,
+    );
+    let contract_info = payment_contract.get_payment_contract_info();
+
+    assert_eq!(registry.get_latest_version(&publisher, &name), 2);
+    assert_eq!(registry.get_template(&publisher, &name, &1), first_version);
+    assert_eq!(
+        contract_info.scope_of_work,
+        "First scope".into_val(&test.env)
+    );
+    assert_eq!(
+        contract_info.company_id,
+        test.payment_contract_info.company_id
+    );
+    assert_eq!(contract_info.project_id, "ID-002".into_val(&test.env));
+    assert_eq!(contract_info.asset_payment_amount, 8);
+    assert_eq!(payment_contract.get_template_reference(), Some(template));
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(1))")]
+fn test_initialize_a_contract_from_a_template_not_published() {
+    let test = PaymentContractTest::setup();
+    let publisher = Address::random(&test.env);
+    let name: Bytes = "Illustration".into_val(&test.env);
+    let registry_id = test.env.register_contract(None, TemplateRegistry {});
+    let registry = TemplateRegistryClient::new(&test.env, &registry_id);
+    let payment_contract = PaymentContractClient::new(
+        &test.env,
+        &test.env.register_contract(None, PaymentContract {}),
+    );
+
+    registry.publish_template(
+        &publisher,
+        &name,
+        &create_template_terms(&test, "First scope"),
+    );
+    payment_contract.initialize_from_template(
+        &TemplateReference {
+            registry: registry_id,
+            publisher,
+            name,
+            version: 2,
+        },
+        &create_contract_deal(&test),
+        &test.creator_address,
+    );
+}
+
+#[test]
+fn test_sign_contract_stores_the_accepted_terms_hash() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    let terms_hash = payment_contract.get_terms_hash();
+
+    assert_eq!(payment_contract.get_accepted_terms_hash(), None);
+    payment_contract.sign_contract(&test.creator_address, &terms_hash, &1681977600);
+
+    assert_eq!(payment_contract.get_accepted_terms_hash(), Some(terms_hash));
+    assert_eq!(payment_contract.get_contract_state(), ContractState::Active);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(38))")]
+fn test_sign_contract_with_a_different_terms_hash() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &BytesN::from_array(&test.env, &[0; 32]),
+        &1681977600,
+    );
+}
+
+#[test]
+fn test_accepted_amendment_updates_the_contract_terms() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.propose_amendment(
+        &contract_manager_address,
+        &vec![
+            &test.env,
+            TermChange::Deadline(1685151703),
+            TermChange::AssetPaymentAmount(7),
+        ],
+    );
+    let terms_hash = payment_contract
+        .get_amendments()
+        .get(0)
+        .unwrap()
+        .unwrap()
+        .terms_hash;
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683100000);
+    payment_contract.accept_amendment(&test.creator_address, &terms_hash);
+    let contract_info = payment_contract.get_payment_contract_info();
+    let amendment = payment_contract.get_amendments().get(0).unwrap().unwrap();
+
+    assert_eq!(contract_info.deadline, 1685151703);
+    assert_eq!(contract_info.asset_payment_amount, 7);
+    assert_eq!(payment_contract.get_terms_hash(), terms_hash);
+    assert_eq!(payment_contract.get_accepted_terms_hash(), Some(terms_hash));
+    assert_eq!(amendment.status, AmendmentStatus::Accepted);
+    assert_eq!(amendment.response_date, 1683100000);
+}
+
+#[test]
+fn test_declined_amendment_keeps_the_contract_terms() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.propose_amendment(
+        &test.creator_address,
+        &vec![&test.env, TermChange::Deadline(1685151703)],
+    );
+    payment_contract.decline_amendment(&test.payment_contract_info.contract_manager.address);
+
+    assert_eq!(
+        payment_contract.get_payment_contract_info().deadline,
+        test.payment_contract_info.deadline
+    );
+    assert_eq!(
+        payment_contract
+            .get_amendments()
+            .get(0)
+            .unwrap()
+            .unwrap()
+            .status,
+        AmendmentStatus::Declined
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(41))")]
+fn test_accept_an_amendment_proposed_by_the_same_party() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.propose_amendment(
+        &test.creator_address,
+        &vec![&test.env, TermChange::Deadline(1685151703)],
+    );
+    let terms_hash = payment_contract
+        .get_amendments()
+        .get(0)
+        .unwrap()
+        .unwrap()
+        .terms_hash;
+    payment_contract.accept_amendment(&test.creator_address, &terms_hash);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(42))")]
+fn test_sign_contract_after_the_offer_expiry() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.offer_expiry = 1681977600;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1681977601);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+}
+
+#[test]
+fn test_accepted_counter_offer_activates_the_contract() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.submit_counter_offer(
+        &test.creator_address,
+        &CounterOfferTerms {
+            asset_payment_amount: 8,
+            deadline: 1685151703,
+        },
+    );
+    let terms_hash = payment_contract.get_counter_offer().unwrap().terms_hash;
+    payment_contract.accept_counter_offer(&1682000000);
+    let contract_info = payment_contract.get_payment_contract_info();
+
+    assert_eq!(payment_contract.get_contract_state(), ContractState::Active);
+    assert_eq!(contract_info.asset_payment_amount, 8);
+    assert_eq!(contract_info.deadline, 1685151703);
+    assert_eq!(payment_contract.get_accepted_terms_hash(), Some(terms_hash));
+    assert_eq!(payment_contract.get_counter_offer(), None);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(43))")]
+fn test_accept_a_rejected_counter_offer() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.submit_counter_offer(
+        &test.creator_address,
+        &CounterOfferTerms {
+            asset_payment_amount: 8,
+            deadline: 1685151703,
+        },
+    );
+    payment_contract.reject_counter_offer();
+    payment_contract.accept_counter_offer(&1682000000);
+}
+
+#[test]
+fn test_cancel_contract_pays_the_approved_assets_and_the_kill_fee() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 604800;
+    payment_contract_info.kill_fee = KillFee::Percentage(5000);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(
+        &map![
+            &test.env,
+            (
+                "ASSET-ID-1".into_val(&test.env),
+                BytesN::from_array(&test.env, &[1; 32])
+            )
+        ],
+        &1683200000,
+    );
+    payment_contract.cancel_contract();
+
+    assert_eq!(
+        payment_contract.get_contract_state(),
+        ContractState::Cancelled
+    );
+    assert_eq!(payment_contract.get_total_paid(), 12);
+    assert_eq!(test.token_client.balance(&test.creator_address), 12);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 988);
+}
+
+#[test]
+fn test_kill_fee_above_the_payout_threshold_waits_for_the_signers() {
+    let test = PaymentContractTest::setup();
+    let signer = Address::random(&test.env);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 604800;
+    payment_contract_info.kill_fee = KillFee::Percentage(5000);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    payment_contract.set_payout_policy(&PayoutPolicy {
+        threshold_amount: 5,
+        signers: vec![&test.env, signer.clone()],
+        required_approvals: 1,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(
+        &map![
+            &test.env,
+            (
+                "ASSET-ID-1".into_val(&test.env),
+                BytesN::from_array(&test.env, &[1; 32])
+            )
+        ],
+        &1683200000,
+    );
+    payment_contract.cancel_contract();
+    let kill_fee_payout = payment_contract
+        .get_pending_payouts()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(kill_fee_payout.amount, 7);
+    assert_eq!(kill_fee_payout.asset_ids, vec![&test.env]);
+    assert_eq!(test.token_client.balance(&test.creator_address), 5);
+
+    payment_contract.approve_payout(&signer, &0);
+
+    assert_eq!(payment_contract.get_total_paid(), 12);
+    assert_eq!(test.token_client.balance(&test.creator_address), 12);
+}
+
+#[test]
+fn test_cancel_contract_without_assets_pays_the_kill_fee() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.kill_fee = KillFee::Flat(3);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.cancel_contract();
+
+    assert_eq!(payment_contract.get_total_paid(), 3);
+    assert_eq!(test.token_client.balance(&test.creator_address), 3);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(3))")]
+fn test_cancel_a_contract_not_signed() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.cancel_contract();
+}
+
+#[test]
+fn test_mutual_termination_pays_the_settlement_and_refunds_the_pending_payouts() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.set_payout_policy(&PayoutPolicy {
+        threshold_amount: 5,
+        signers: vec![&test.env, Address::random(&test.env)],
+        required_approvals: 1,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 990);
+
+    payment_contract.propose_settlement(&test.creator_address, &4);
+    payment_contract.accept_settlement(&contract_manager_address, &4);
+    let asset: Asset = payment_contract
+        .get_submitted_assets()
+        .values()
+        .get(0)
+        .unwrap()
+        .unwrap();
+    let history = payment_contract.get_contract_history();
+
+    assert_eq!(
+        payment_contract.get_contract_state(),
+        ContractState::Finished
+    );
+    assert_eq!(asset.state, AssetState::Approved);
+    assert_eq!(payment_contract.get_pending_payouts().len(), 0);
+    assert_eq!(test.token_client.balance(&test.creator_address), 4);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 996);
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history.get_unchecked(1).unwrap().action,
+        ContractAction::Terminated(4)
+    );
+    assert_eq!(
+        history.get_unchecked(1).unwrap().actor,
+        contract_manager_address
+    );
+}
+
+#[test]
+fn test_creator_accepts_the_settlement_funded_by_the_company() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    let payment_contract_address =
+        Address::from_contract_id(&test.env, &payment_contract.contract_id);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.propose_settlement(&contract_manager_address, &4);
+    assert_eq!(test.token_client.balance(&payment_contract_address), 4);
+
+    payment_contract.accept_settlement(&test.creator_address, &4);
+    let authorizations = test.env.recorded_top_authorizations();
+
+    assert_eq!(authorizations.len(), 1);
+    assert_eq!(authorizations[0].0, test.creator_address);
+    assert_eq!(test.token_client.balance(&test.creator_address), 4);
+    assert_eq!(test.token_client.balance(&payment_contract_address), 0);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 996);
+}
+
+#[test]
+fn test_new_settlement_returns_the_held_funds_to_the_project() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let project_id = test.payment_contract_info.project_id.clone();
+    let registry_id = create_project_registry(&test, 100);
+    let registry = ProjectRegistryClient::new(&test.env, &registry_id);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.project_registry = LinkedContract::Id(registry_id);
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let payment_contract_address =
+        Address::from_contract_id(&test.env, &payment_contract.contract_id);
+
+    registry.commit_budget(&project_id, &payment_contract_address, &30);
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.propose_settlement(&contract_manager_address, &7);
+    assert_eq!(registry.get_budget_report(&project_id).paid, 7);
+
+    payment_contract.propose_settlement(&contract_manager_address, &4);
+    assert_eq!(registry.get_budget_report(&project_id).paid, 4);
+    assert_eq!(test.token_client.balance(&payment_contract_address), 4);
+
+    payment_contract.accept_settlement(&test.creator_address, &4);
+
+    assert_eq!(test.token_client.balance(&test.creator_address), 4);
+    assert_eq!(test.token_client.balance(&payment_contract_address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(46))")]
+fn test_accept_a_settlement_with_a_different_amount() {
+    let test = PaymentContractTest::setup();
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.propose_settlement(&test.creator_address, &4);
+    payment_contract.accept_settlement(&test.payment_contract_info.contract_manager.address, &2);
+}
+
+#[test]
+fn test_finalize_review_approves_and_pays_from_the_escrow() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.review_window = 86400;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    let asset_ids = vec![
+        &test.env,
+        "ASSET-ID-1".into_val(&test.env),
+        "ASSET-ID-2".into_val(&test.env),
+    ];
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683158399);
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.fund_escrow(&10);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 990);
+
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683244800);
+    payment_contract.finalize_review(&asset_ids);
+    let asset: Asset = payment_contract
+        .get_submitted_assets()
+        .values()
+        .get(0)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(asset.state, AssetState::Paid);
+    assert_eq!(payment_contract.get_escrow(), 0);
+    assert_eq!(test.token_client.balance(&test.creator_address), 10);
+}
+
+#[test]
+fn test_finalize_review_without_escrow_leaves_the_assets_approved() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.review_window = 86400;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = u64::MAX);
+    payment_contract.finalize_review(&vec![&test.env, "ASSET-ID-1".into_val(&test.env)]);
+    let asset: Asset = payment_contract
+        .get_submitted_assets()
+        .get("ASSET-ID-1".into_val(&test.env))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(asset.state, AssetState::Approved);
+    assert_eq!(test.token_client.balance(&test.creator_address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(21))")]
+fn test_finalize_review_before_the_review_window_is_over() {
+    let test = PaymentContractTest::setup();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.review_window = 86400;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683158399);
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 1683244799);
+    payment_contract.finalize_review(&vec![&test.env, "ASSET-ID-1".into_val(&test.env)]);
+}
+
+#[test]
+fn test_cancel_contract_refunds_the_escrow() {
+    let test = PaymentContractTest::setup();
+    let contract_manager_address = test.payment_contract_info.contract_manager.address.clone();
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 604800;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.approve_asset(&test.asset_hashes, &1683200000);
+    payment_contract.fund_escrow(&5);
+    payment_contract.cancel_contract();
+
+    assert_eq!(payment_contract.get_escrow(), 0);
+    assert_eq!(test.token_client.balance(&test.creator_address), 10);
+    assert_eq!(test.token_client.balance(&contract_manager_address), 990);
+}
+
+#[test]
+fn test_asset_is_approved_once_the_reviewer_quorum_is_met() {
+    let test = PaymentContractTest::setup();
+    let first_reviewer = Address::random(&test.env);
+    let second_reviewer = Address::random(&test.env);
+    let third_reviewer = Address::random(&test.env);
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.payment_time = 604800;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    payment_contract.set_review_policy(&ReviewPolicy {
+        reviewers: vec![
+            &test.env,
+            first_reviewer.clone(),
+            second_reviewer.clone(),
+            third_reviewer.clone(),
+        ],
+        required_approvals: 2,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.vote_on_asset(&first_reviewer, &asset_id, &ReviewVote::Approve);
+    payment_contract.vote_on_asset(&second_reviewer, &asset_id, &ReviewVote::Reject);
+    payment_contract.vote_on_asset(&third_reviewer, &asset_id, &ReviewVote::Approve);
+    payment_contract.approve_asset(
+        &map![
+            &test.env,
+            (asset_id.clone(), BytesN::from_array(&test.env, &[1; 32]))
+        ],
+        &1683200000,
+    );
+    let votes = payment_contract.get_review_votes(&asset_id);
+
+    assert_eq!(votes.len(), 3);
+    assert_eq!(
+        votes.get(second_reviewer).unwrap().unwrap(),
+        ReviewVote::Reject
+    );
+    assert_eq!(
+        payment_contract
+            .get_submitted_assets()
+            .get(asset_id)
+            .unwrap()
+            .unwrap()
+            .state,
+        AssetState::Approved
+    );
+}
+
+#[test]
+fn test_blocking_reject_votes_reject_the_asset() {
+    let test = PaymentContractTest::setup();
+    let first_reviewer = Address::random(&test.env);
+    let second_reviewer = Address::random(&test.env);
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.set_review_policy(&ReviewPolicy {
+        reviewers: vec![
+            &test.env,
+            first_reviewer.clone(),
+            second_reviewer.clone(),
+            Address::random(&test.env),
+        ],
+        required_approvals: 2,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.vote_on_asset(&first_reviewer, &asset_id, &ReviewVote::Reject);
+    let asset_state = |payment_contract: &PaymentContractClient| {
+        payment_contract
+            .get_submitted_assets()
+            .get(asset_id.clone())
+            .unwrap()
+            .unwrap()
+            .state
+    };
+    assert_eq!(asset_state(&payment_contract), AssetState::InReview);
+
+    payment_contract.vote_on_asset(&second_reviewer, &asset_id, &ReviewVote::Reject);
+
+    assert_eq!(asset_state(&payment_contract), AssetState::Rejected);
+}
+
+#[test]
+fn test_finalize_review_skips_the_assets_without_the_reviewer_quorum() {
+    let test = PaymentContractTest::setup();
+    let reviewer = Address::random(&test.env);
+    let mut payment_contract_info = test.payment_contract_info.clone();
+    payment_contract_info.review_window = 86400;
+    let payment_contract =
+        create_payment_contract(&test.env, &payment_contract_info, &test.creator_address);
+    payment_contract.set_review_policy(&ReviewPolicy {
+        reviewers: vec![&test.env, reviewer.clone()],
+        required_approvals: 1,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.vote_on_asset(
+        &reviewer,
+        &"ASSET-ID-1".into_val(&test.env),
+        &ReviewVote::Approve,
+    );
+    test.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp = 86401);
+    payment_contract.finalize_review(&vec![
+        &test.env,
+        "ASSET-ID-1".into_val(&test.env),
+        "ASSET-ID-2".into_val(&test.env),
+    ]);
+    let assets = payment_contract.get_submitted_assets();
+
+    assert_eq!(
+        assets
+            .get("ASSET-ID-1".into_val(&test.env))
+            .unwrap()
+            .unwrap()
+            .state,
+        AssetState::Approved
+    );
+    assert_eq!(
+        assets
+            .get("ASSET-ID-2".into_val(&test.env))
+            .unwrap()
+            .unwrap()
+            .state,
+        AssetState::InReview
+    );
+}
+
+#[test]
+#[should_panic(expected = "Status(ContractError(49))")]
+fn test_approve_asset_without_the_reviewer_quorum() {
+    let test = PaymentContractTest::setup();
+    let reviewer = Address::random(&test.env);
+    let asset_id: Bytes = "ASSET-ID-1".into_val(&test.env);
+    let payment_contract = create_payment_contract(
+        &test.env,
+        &test.payment_contract_info,
+        &test.creator_address,
+    );
+    payment_contract.set_review_policy(&ReviewPolicy {
+        reviewers: vec![&test.env, reviewer.clone(), Address::random(&test.env)],
+        required_approvals: 2,
+    });
+
+    payment_contract.sign_contract(
+        &test.creator_address,
+        &payment_contract.get_terms_hash(),
+        &1681977600,
+    );
+    payment_contract.submit_asset(&test.creator_address, &test.assets);
+    payment_contract.vote_on_asset(&reviewer, &asset_id, &ReviewVote::Approve);
+    payment_contract.approve_asset(
+        &map![
+            &test.env,
+            (asset_id, BytesN::from_array(&test.env, &[1; 32]))
+        ],
+        &1683200000,
+    );
 }
\ No newline at end of file